@@ -0,0 +1,191 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+use std::time::Duration;
+
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::{Cell, Color, ContentArrangement, Table};
+
+use crate::parameters::ParameterAssignment;
+use crate::registry::Registration;
+use crate::runner::{input_path, run_file, Part, PartSolution, RunError};
+
+#[cfg(test)]
+mod tests;
+
+/// Expected answers, stored per year, day, part and input file.
+///
+/// The file format is line based, every line has the form `<year> <day> <part> <input file> <answer>`.
+/// Empty lines and lines starting with `#` are ignored.
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct Answers {
+    answers: BTreeMap<AnswerKey, String>,
+}
+
+/// Year, day, part and input file of an answer
+type AnswerKey = (usize, usize, usize, String);
+
+/// Split the first whitespace separated field of a line from the rest of it
+fn split_field(line: &str) -> Option<(&str, &str)> {
+    line.trim_start().split_once(char::is_whitespace)
+}
+
+/// Parse a `<year> <day> <part> <input file> <answer>` line, the answer is the rest of the line and may contain spaces
+fn parse_line(line: &str) -> Option<(AnswerKey, &str)> {
+    let (year, line) = split_field(line)?;
+    let (day, line) = split_field(line)?;
+    let (part, line) = split_field(line)?;
+    let (input, answer) = split_field(line)?;
+    let key = (
+        year.parse().ok()?,
+        day.parse().ok()?,
+        part.parse().ok()?,
+        input.to_string(),
+    );
+
+    Some((key, answer.trim()))
+}
+
+impl Answers {
+    /// Load the answers from a file. A missing file is considered as an empty one.
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, Error> {
+        let mut answers = BTreeMap::new();
+
+        for (i_line, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            match parse_line(line) {
+                Some((key, answer)) => answers.insert(key, answer.to_string()),
+                None => {
+                    return Err(Error::new(
+                        ErrorKind::InvalidData,
+                        format!(
+                            "line {}: expected `<year> <day> <part> <input file> <answer>`",
+                            i_line + 1
+                        ),
+                    ))
+                }
+            };
+        }
+
+        Ok(Self { answers })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, year: usize, day: usize, part: usize, input: &str) -> Option<&str> {
+        self.answers
+            .get(&(year, day, part, input.to_string()))
+            .map(|answer| answer.as_str())
+    }
+
+    pub fn insert(&mut self, year: usize, day: usize, part: usize, input: &str, answer: String) {
+        self.answers.insert((year, day, part, input.to_string()), answer);
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "# <year> <day> <part> <input file> <answer>")?;
+        for ((year, day, part, input), answer) in &self.answers {
+            writeln!(f, "{year} {day:0>2} {part} {input} {answer}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Outcome of the verification of the answers of several days
+#[derive(Debug)]
+pub struct Verification {
+    /// Expected and actual answer of every part
+    pub table: Table,
+    /// Errors of the days that could not be run
+    pub errors: Vec<RunError>,
+    /// Whether a day could not be run, a part panicked or timed out or an answer differs from the expected one
+    pub failed: bool,
+}
+
+/// Run the solutions on their input with the given parameters and timeout, see `run_day`, and compare their answers
+/// with the expected ones. If `record` is set, the answers are recorded in `answers` instead of being compared.
+/// The answers are not keyed by the parameters, the answers of non-default parameters are kept in their own file.
+pub fn verify(
+    registrations: &[&Registration],
+    answers: &mut Answers,
+    parameters: &[ParameterAssignment],
+    timeout: Option<Duration>,
+    use_real_input: bool,
+    record: bool,
+) -> Verification {
+    let mut table = Table::new();
+    let mut errors = vec![];
+    let mut failed = false;
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec!["Day", "Part", "Input", "Expected", "Actual", "Status"]);
+    for registration in registrations {
+        let (year, day) = (registration.metadata.year, registration.metadata.day);
+        let path = input_path(year, day, use_real_input);
+        let report = match run_file(registration, Part::Both, parameters, timeout, &path) {
+            Ok(report) => report,
+            Err(error) => {
+                errors.push(error);
+                failed = true;
+                continue;
+            }
+        };
+
+        for (part, part_solution) in [(1, report.part_01_sol), (2, report.part_02_sol)] {
+            let actual = part_solution.to_string();
+            let expected = answers.get(year, day, part, &path).map(|expected| expected.to_string());
+            let status = if matches!(part_solution, PartSolution::Panicked(_)) {
+                failed = true;
+                Cell::new("panicked").fg(Color::Red)
+            } else if part_solution == PartSolution::TimedOut {
+                failed = true;
+                Cell::new("timed out").fg(Color::Red)
+            } else if part_solution == PartSolution::Unimplemented {
+                Cell::new("unimplemented").fg(Color::DarkGrey)
+            } else if record {
+                answers.insert(year, day, part, &path, actual.clone());
+                Cell::new("recorded").fg(Color::Blue)
+            } else {
+                match &expected {
+                    None => Cell::new("missing").fg(Color::Yellow),
+                    Some(expected) if *expected == actual => Cell::new("pass").fg(Color::Green),
+                    Some(_) => {
+                        failed = true;
+                        Cell::new("fail").fg(Color::Red)
+                    }
+                }
+            };
+
+            table.add_row(vec![
+                Cell::new(day),
+                Cell::new(part),
+                Cell::new(&path),
+                Cell::new(expected.unwrap_or_default()),
+                Cell::new(actual),
+                status,
+            ]);
+        }
+    }
+
+    Verification { table, errors, failed }
+}
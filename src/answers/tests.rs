@@ -0,0 +1,67 @@
+use std::io::ErrorKind;
+
+use crate::answers::{verify, Answers};
+use crate::parameters::ParameterAssignment;
+use crate::year2023::day11::REGISTRATION;
+
+#[test]
+fn test_round_trip() {
+    let mut answers = Answers::default();
+    answers.insert(2023, 7, 1, "inputs/2023/day07", "6440".to_string());
+    answers.insert(2023, 7, 2, "inputs/2023/day07", "5905".to_string());
+    answers.insert(2023, 12, 1, "input_examples/2023/day12", "a b".to_string());
+    answers.insert(2024, 1, 1, "inputs/2024/day01", "11".to_string());
+
+    let content = answers.to_string();
+    assert_eq!(
+        content,
+        "# <year> <day> <part> <input file> <answer>\n\
+        2023 07 1 inputs/2023/day07 6440\n\
+        2023 07 2 inputs/2023/day07 5905\n\
+        2023 12 1 input_examples/2023/day12 a b\n\
+        2024 01 1 inputs/2024/day01 11\n"
+    );
+    assert_eq!(Answers::parse(&content).unwrap(), answers);
+    assert_eq!(answers.get(2023, 12, 1, "input_examples/2023/day12"), Some("a b"));
+    assert_eq!(answers.get(2024, 7, 1, "inputs/2023/day07"), None);
+}
+
+#[test]
+fn test_repeated_spaces() {
+    let answers =
+        Answers::parse("2023  07\t1   inputs/2023/day07  6440\n2023 12 1 input_examples/2023/day12  a b \n").unwrap();
+    assert_eq!(answers.get(2023, 7, 1, "inputs/2023/day07"), Some("6440"));
+    assert_eq!(answers.get(2023, 12, 1, "input_examples/2023/day12"), Some("a b"));
+}
+
+#[test]
+fn test_malformed_lines() {
+    for content in [
+        "2023 07 1 inputs/2023/day07",
+        "2023 x 1 inputs/2023/day07 6440",
+        "07 1 inputs/2023/day07 6440",
+    ] {
+        let error = Answers::parse(content).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData, "{content}");
+    }
+}
+
+#[test]
+fn test_verify_parameters() {
+    // The example answer of part two with an expansion of 10 instead of 1000000
+    let path = "input_examples/2023/day11";
+    let mut answers = Answers::default();
+    answers.insert(2023, 11, 1, path, "374".to_string());
+    answers.insert(2023, 11, 2, path, "1030".to_string());
+    let parameters = [ParameterAssignment {
+        name: "expansion".to_string(),
+        value: "10".to_string(),
+    }];
+    assert!(!verify(&[&REGISTRATION], &mut answers, &parameters, None, false, false).failed);
+    assert!(verify(&[&REGISTRATION], &mut answers, &[], None, false, false).failed);
+
+    // The recorded answers are the ones of these parameters
+    let mut recorded = Answers::default();
+    verify(&[&REGISTRATION], &mut recorded, &parameters, None, false, true);
+    assert_eq!(recorded, answers);
+}
@@ -0,0 +1,52 @@
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::{Cell, Color, ContentArrangement, Table};
+
+#[cfg(test)]
+mod tests;
+
+/// Result of the check of a property a solution expects from its input
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Assumption {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Explanation of why the assumption does not hold, `None` if it holds
+    pub failure: Option<String>,
+}
+
+impl Assumption {
+    /// Create the result of a check, `explain` is only called if the assumption does not hold
+    pub fn check(name: &'static str, description: &'static str, holds: bool, explain: impl FnOnce() -> String) -> Self {
+        Self {
+            name,
+            description,
+            failure: (!holds).then(explain),
+        }
+    }
+
+    pub fn holds(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// Table of the results of the checks of the assumptions
+pub fn assumptions_table(assumptions: &[Assumption]) -> Table {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec!["Assumption", "Description", "Status", "Explanation"]);
+    for assumption in assumptions {
+        table.add_row(vec![
+            Cell::new(assumption.name),
+            Cell::new(assumption.description),
+            if assumption.holds() {
+                Cell::new("holds").fg(Color::Green)
+            } else {
+                Cell::new("fails").fg(Color::Red)
+            },
+            Cell::new(assumption.failure.as_deref().unwrap_or_default()),
+        ]);
+    }
+
+    table
+}
@@ -0,0 +1,73 @@
+use crate::assumptions::{assumptions_table, Assumption};
+use crate::models::{AdventSolution, Answer};
+use crate::parser::ParseError;
+use crate::registry::Registration;
+use crate::runner::{check_input, RunError};
+
+/// Solution assuming that its input is sorted, `invalid` cannot be parsed
+#[derive(Default)]
+struct Stub {
+    input: String,
+}
+
+impl AdventSolution for Stub {
+    fn parse(&mut self, data: String) -> Result<(), ParseError> {
+        if data == "invalid" {
+            return Err(ParseError::new(1, &data, 0, "a valid input"));
+        }
+        self.input = data;
+
+        Ok(())
+    }
+
+    fn solve_part_one(&self) -> Answer {
+        Answer::Unimplemented
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        Answer::Unimplemented
+    }
+
+    fn check_assumptions(&self) -> Vec<Assumption> {
+        let sorted = self.input.as_bytes().is_sorted();
+        vec![Assumption::check("sorted", "The input must be sorted", sorted, || {
+            format!("`{}` is not sorted", self.input)
+        })]
+    }
+}
+
+const STUB: Registration = Registration::new::<Stub>(2024, 1, "Stub", [false, false]);
+
+#[test]
+fn test_check() {
+    let assumption = Assumption::check("sorted", "The input must be sorted", true, || unreachable!());
+    assert!(assumption.holds());
+    assert_eq!(assumption.failure, None);
+
+    let assumption = Assumption::check("sorted", "The input must be sorted", false, || "unsorted".to_string());
+    assert!(!assumption.holds());
+    assert_eq!(assumption.failure, Some("unsorted".to_string()));
+}
+
+#[test]
+fn test_check_input() {
+    let assumptions = check_input(&STUB, "abc".to_string()).unwrap();
+    assert_eq!(assumptions.len(), 1);
+    assert!(assumptions[0].holds());
+
+    let assumptions = check_input(&STUB, "cab".to_string()).unwrap();
+    assert_eq!(assumptions[0].failure, Some("`cab` is not sorted".to_string()));
+
+    assert!(matches!(
+        check_input(&STUB, "invalid".to_string()),
+        Err(RunError::Parse(error)) if error.day == 1
+    ));
+}
+
+#[test]
+fn test_assumptions_table() {
+    let assumptions = check_input(&STUB, "cab".to_string()).unwrap();
+    let table = assumptions_table(&assumptions).to_string();
+    assert!(table.contains("fails"));
+    assert!(table.contains("`cab` is not sorted"));
+}
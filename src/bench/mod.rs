@@ -0,0 +1,318 @@
+use std::fs;
+use std::hint::black_box;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+use std::time::Instant;
+
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::{Cell, Color, ContentArrangement, Table};
+use serde::{Deserialize, Serialize};
+
+use crate::parameters::ParameterAssignment;
+use crate::registry::Registration;
+use crate::runner::{catch_panic, configure, input_path, read_input, RunError};
+
+#[cfg(test)]
+mod tests;
+
+/// Statistics over a set of timing samples, every value is in microseconds.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub p95: f64,
+    pub std_dev: f64,
+}
+
+impl Stats {
+    pub fn new(mut samples: Vec<f64>) -> Self {
+        assert!(!samples.is_empty(), "Cannot compute statistics without samples");
+        samples.sort_by(f64::total_cmp);
+
+        let count = samples.len();
+        let mean = samples.iter().sum::<f64>() / count as f64;
+        let median = if count % 2 == 0 {
+            (samples[count / 2 - 1] + samples[count / 2]) / 2.0
+        } else {
+            samples[count / 2]
+        };
+        // Nearest-rank percentile
+        let p95 = samples[((count as f64 * 0.95).ceil() as usize).max(1) - 1];
+        let std_dev = if count > 1 {
+            (samples.iter().map(|sample| (sample - mean).powi(2)).sum::<f64>() / (count - 1) as f64).sqrt()
+        } else {
+            0.0
+        };
+
+        Self {
+            min: samples[0],
+            median,
+            mean,
+            p95,
+            std_dev,
+        }
+    }
+}
+
+/// Reason why a phase was not measured
+#[derive(Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Unmeasured {
+    /// The part has no solution
+    Unimplemented,
+    /// Message of the panic that occurred while running the phase
+    Panicked(String),
+    /// A previous phase panicked
+    Skipped,
+}
+
+/// Benchmark of a phase, serialized as the statistics when it was measured
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PhaseBench {
+    Measured(Stats),
+    Unmeasured(Unmeasured),
+}
+
+impl PhaseBench {
+    pub fn stats(&self) -> Option<&Stats> {
+        match self {
+            PhaseBench::Measured(stats) => Some(stats),
+            PhaseBench::Unmeasured(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct DayBench {
+    pub year: usize,
+    pub day: usize,
+    pub parse: PhaseBench,
+    pub prep: PhaseBench,
+    pub part_01: PhaseBench,
+    pub part_02: PhaseBench,
+}
+
+impl DayBench {
+    pub fn phases(&self) -> [(&'static str, &PhaseBench); 4] {
+        [
+            ("parse", &self.parse),
+            ("prep", &self.prep),
+            ("part 1", &self.part_01),
+            ("part 2", &self.part_02),
+        ]
+    }
+}
+
+/// Benchmarks saved as a JSON array of `DayBench`, to compare new benchmarks with
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Baseline {
+    pub benches: Vec<DayBench>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        Self::parse(&fs::read_to_string(path)?).map_err(|error| Error::new(ErrorKind::InvalidData, error))
+    }
+
+    pub fn parse(content: &str) -> Result<Self, serde_json::Error> {
+        Ok(Self {
+            benches: serde_json::from_str(content)?,
+        })
+    }
+
+    pub fn save(benches: &[DayBench], path: &Path) -> Result<(), Error> {
+        fs::write(path, serde_json::to_string_pretty(benches).unwrap())
+    }
+
+    pub fn find(&self, year: usize, day: usize) -> Option<&DayBench> {
+        self.benches.iter().find(|bench| bench.year == year && bench.day == day)
+    }
+}
+
+fn elapsed_micros(now: Instant) -> f64 {
+    now.elapsed().as_secs_f64() * 1_000_000.0
+}
+
+/// Run every phase of a solution `iterations` times after `warmup` ignored runs, with the given parameters.
+/// A new solution is created for every run. A panic stops the measure of its phase and of the following ones, except
+/// for the parts that do not depend on each other. The parts without solution are not run.
+pub fn bench_day(
+    registration: &Registration,
+    parameters: &[ParameterAssignment],
+    input: &str,
+    warmup: usize,
+    iterations: usize,
+) -> Result<DayBench, RunError> {
+    let metadata = registration.metadata;
+    let mut samples: [Vec<f64>; 4] = Default::default();
+    let mut unmeasured: [Option<Unmeasured>; 4] = [
+        None,
+        None,
+        (!metadata.is_implemented(1)).then_some(Unmeasured::Unimplemented),
+        (!metadata.is_implemented(2)).then_some(Unmeasured::Unimplemented),
+    ];
+    // Mark a phase as panicked, the phases depending on it cannot be run anymore. The phases run before keep the
+    // times of this run when it is the first one, even during the warmup, to have at least one sample
+    let stop = |unmeasured: &mut [Option<Unmeasured>; 4],
+                samples: &mut [Vec<f64>; 4],
+                times: [f64; 4],
+                phase: usize,
+                message: String| {
+        for (phase_samples, time) in samples.iter_mut().zip(times).take(phase) {
+            if phase_samples.is_empty() {
+                phase_samples.push(time);
+            }
+        }
+        unmeasured[phase] = Some(Unmeasured::Panicked(message));
+        for later in unmeasured.iter_mut().skip(phase + 1).filter(|later| later.is_none()) {
+            *later = Some(Unmeasured::Skipped);
+        }
+    };
+
+    for iteration in 0..warmup + iterations {
+        let mut solution = (registration.new_solution)();
+        configure(solution.as_mut(), parameters).map_err(|error| RunError::Parameter {
+            day: metadata.day,
+            error,
+        })?;
+        let mut times = [0.0; 4];
+
+        let now = Instant::now();
+        match catch_panic(|| solution.parse(input.to_string())) {
+            Ok(result) => result?,
+            Err(message) => {
+                stop(&mut unmeasured, &mut samples, times, 0, message);
+                break;
+            }
+        }
+        times[0] = elapsed_micros(now);
+
+        let now = Instant::now();
+        if let Err(message) = catch_panic(|| solution.prepare()) {
+            stop(&mut unmeasured, &mut samples, times, 1, message);
+            break;
+        }
+        times[1] = elapsed_micros(now);
+
+        for phase in [2, 3] {
+            if unmeasured[phase].is_some() {
+                continue;
+            }
+            let now = Instant::now();
+            let result = catch_panic(|| match phase {
+                2 => black_box(solution.solve_part_one()),
+                _ => black_box(solution.solve_part_two()),
+            });
+            times[phase] = elapsed_micros(now);
+            if let Err(message) = result {
+                unmeasured[phase] = Some(Unmeasured::Panicked(message));
+            }
+        }
+
+        if iteration >= warmup {
+            for (phase_samples, time) in samples.iter_mut().zip(times) {
+                phase_samples.push(time);
+            }
+        }
+    }
+
+    let [parse, prep, part_01, part_02] = [0, 1, 2, 3].map(|phase| match unmeasured[phase].take() {
+        Some(unmeasured) => PhaseBench::Unmeasured(unmeasured),
+        None => PhaseBench::Measured(Stats::new(std::mem::take(&mut samples[phase]))),
+    });
+    Ok(DayBench {
+        year: metadata.year,
+        day: metadata.day,
+        parse,
+        prep,
+        part_01,
+        part_02,
+    })
+}
+
+/// Benchmark several solutions on their input, see `bench_day`
+pub fn bench_days(
+    registrations: &[&Registration],
+    parameters: &[ParameterAssignment],
+    use_real_input: bool,
+    warmup: usize,
+    iterations: usize,
+) -> Vec<Result<DayBench, RunError>> {
+    registrations
+        .iter()
+        .map(|registration| {
+            let (year, day) = (registration.metadata.year, registration.metadata.day);
+            let input = read_input(&input_path(year, day, use_real_input))?;
+            bench_day(registration, parameters, &input, warmup, iterations)
+        })
+        .collect()
+}
+
+/// Table of the statistics of every phase of the benchmarks, compared with the baseline when it has the day.
+/// The phases that were not measured are listed with the reason why.
+/// Returns whether the median of a phase is more than `threshold` percent slower than in the baseline.
+pub fn bench_table(benches: &[DayBench], baseline: &Baseline, threshold: u32) -> (Table, bool) {
+    let mut table = Table::new();
+    let mut regression = false;
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            "Year", "Day", "Phase", "Min", "Median", "Mean", "P95", "Std dev", "Baseline", "Change",
+        ]);
+    for bench in benches {
+        let baseline_bench = baseline.find(bench.year, bench.day);
+        for (i_phase, (phase, phase_bench)) in bench.phases().into_iter().enumerate() {
+            let stats = match phase_bench {
+                PhaseBench::Measured(stats) => stats,
+                PhaseBench::Unmeasured(unmeasured) => {
+                    let status = match unmeasured {
+                        Unmeasured::Unimplemented => Cell::new("unimplemented").fg(Color::DarkGrey),
+                        Unmeasured::Panicked(message) => Cell::new(format!("panicked: {message}")).fg(Color::Red),
+                        Unmeasured::Skipped => Cell::new("-"),
+                    };
+                    table.add_row(vec![
+                        Cell::new(bench.year),
+                        Cell::new(bench.day),
+                        Cell::new(phase),
+                        status,
+                    ]);
+                    continue;
+                }
+            };
+            let baseline_median = baseline_bench
+                .and_then(|baseline_bench| baseline_bench.phases()[i_phase].1.stats())
+                .map(|stats| stats.median);
+            let change = match baseline_median {
+                // A phase that took no measurable time cannot be compared
+                None | Some(0.0) => Cell::new(""),
+                Some(baseline_median) => {
+                    let change = (stats.median - baseline_median) / baseline_median * 100.0;
+                    if change > threshold as f64 {
+                        regression = true;
+                        Cell::new(format!("{change:+.1}%")).fg(Color::Red)
+                    } else {
+                        Cell::new(format!("{change:+.1}%"))
+                    }
+                }
+            };
+
+            table.add_row(vec![
+                Cell::new(bench.year),
+                Cell::new(bench.day),
+                Cell::new(phase),
+                Cell::new(format!("{:.1}", stats.min)),
+                Cell::new(format!("{:.1}", stats.median)),
+                Cell::new(format!("{:.1}", stats.mean)),
+                Cell::new(format!("{:.1}", stats.p95)),
+                Cell::new(format!("{:.1}", stats.std_dev)),
+                Cell::new(baseline_median.map(|median| format!("{median:.1}")).unwrap_or_default()),
+                change,
+            ]);
+        }
+    }
+
+    (table, regression)
+}
@@ -0,0 +1,176 @@
+use itertools::Itertools;
+
+use crate::bench::{bench_day, bench_table, Baseline, DayBench, PhaseBench, Stats, Unmeasured};
+use crate::models::{AdventSolution, Answer};
+use crate::parser::ParseError;
+use crate::registry::Registration;
+use crate::runner::RunError;
+
+fn stats(value: f64) -> Stats {
+    Stats::new(vec![value])
+}
+
+/// Solution panicking in the phase named by its input, part two always answers 2
+#[derive(Default)]
+struct Stub {
+    input: String,
+}
+
+impl AdventSolution for Stub {
+    fn parse(&mut self, data: String) -> Result<(), ParseError> {
+        match data.as_str() {
+            "invalid" => return Err(ParseError::new(1, &data, 0, "a valid input")),
+            "parse" => panic!("parse panicked"),
+            _ => self.input = data,
+        }
+
+        Ok(())
+    }
+
+    fn prepare(&mut self) {
+        if self.input == "prep" {
+            panic!("prep panicked");
+        }
+    }
+
+    fn solve_part_one(&self) -> Answer {
+        if self.input == "part one" {
+            panic!("part one panicked");
+        }
+
+        self.input.len().into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        2.into()
+    }
+}
+
+const STUB: Registration = Registration::new::<Stub>(2024, 3, "Stub", [true, true]);
+
+/// Names of the phases that were not measured with the reason why
+fn unmeasured(bench: &DayBench) -> Vec<(&'static str, Unmeasured)> {
+    bench
+        .phases()
+        .into_iter()
+        .filter_map(|(phase, phase_bench)| match phase_bench {
+            PhaseBench::Measured(stats) => {
+                assert!(stats.min <= stats.median);
+                None
+            }
+            PhaseBench::Unmeasured(unmeasured) => Some((phase, unmeasured.clone())),
+        })
+        .collect()
+}
+
+#[test]
+fn test_stats() {
+    let even = Stats::new(vec![4.0, 1.0, 3.0, 2.0]);
+    assert_eq!((even.min, even.median, even.mean, even.p95), (1.0, 2.5, 2.5, 4.0));
+    assert!((even.std_dev - (5.0f64 / 3.0).sqrt()).abs() < 1e-9);
+
+    let odd = Stats::new(vec![5.0, 1.0, 3.0]);
+    assert_eq!(
+        (odd.min, odd.median, odd.mean, odd.p95, odd.std_dev),
+        (1.0, 3.0, 3.0, 5.0, 2.0)
+    );
+
+    // The nearest-rank percentile is a sample, not an interpolation
+    assert_eq!(Stats::new((1..=20).map(f64::from).collect()).p95, 19.0);
+    assert_eq!(Stats::new((1..=21).map(f64::from).collect()).p95, 20.0);
+
+    assert_eq!(
+        stats(7.0),
+        Stats {
+            min: 7.0,
+            median: 7.0,
+            mean: 7.0,
+            p95: 7.0,
+            std_dev: 0.0,
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Cannot compute statistics without samples")]
+fn test_stats_without_samples() {
+    Stats::new(vec![]);
+}
+
+#[test]
+fn test_baseline() {
+    let bench = |year, day| DayBench {
+        year,
+        day,
+        parse: PhaseBench::Measured(stats(1.0)),
+        prep: PhaseBench::Measured(stats(2.0)),
+        part_01: PhaseBench::Measured(stats(3.0)),
+        part_02: PhaseBench::Unmeasured(Unmeasured::Panicked("part two panicked".to_string())),
+    };
+    let benches = vec![bench(2023, 7), bench(2024, 7)];
+    let baseline = Baseline::parse(&serde_json::to_string(&benches).unwrap()).unwrap();
+    assert_eq!(
+        baseline,
+        Baseline {
+            benches: benches.clone()
+        }
+    );
+    assert_eq!(baseline.find(2024, 7), Some(&benches[1]));
+    assert_eq!(baseline.find(2024, 8), None);
+
+    // The year is required
+    let mut without_year = serde_json::to_value(&benches).unwrap();
+    without_year[0].as_object_mut().unwrap().remove("year");
+    assert!(Baseline::parse(&without_year.to_string()).is_err());
+
+    // The rows of the same day of different years are told apart by their year
+    let (table, regression) = bench_table(&benches, &baseline, 10);
+    assert!(!regression);
+    let days: Vec<_> = table
+        .row_iter()
+        .map(|row| row.cell_iter().take(2).map(|cell| cell.content()).collect::<Vec<_>>())
+        .dedup()
+        .collect();
+    assert_eq!(days, vec![vec!["2023", "7"], vec!["2024", "7"]]);
+}
+
+#[test]
+fn test_bench_day() {
+    let bench = bench_day(&STUB, &[], "input", 1, 3).unwrap();
+    assert_eq!((bench.year, bench.day), (2024, 3));
+    assert_eq!(unmeasured(&bench), vec![]);
+
+    // A panic stops the phases depending on the panicking one
+    let bench = bench_day(&STUB, &[], "part one", 1, 3).unwrap();
+    assert_eq!(
+        unmeasured(&bench),
+        vec![("part 1", Unmeasured::Panicked("part one panicked".to_string()))]
+    );
+    let bench = bench_day(&STUB, &[], "prep", 1, 3).unwrap();
+    assert_eq!(
+        unmeasured(&bench),
+        vec![
+            ("prep", Unmeasured::Panicked("prep panicked".to_string())),
+            ("part 1", Unmeasured::Skipped),
+            ("part 2", Unmeasured::Skipped)
+        ]
+    );
+
+    // The parts without solution are not run
+    let registration = Registration::new::<Stub>(2024, 3, "Stub", [false, true]);
+    let bench = bench_day(&registration, &[], "parse", 0, 1).unwrap();
+    assert_eq!(
+        unmeasured(&bench),
+        vec![
+            ("parse", Unmeasured::Panicked("parse panicked".to_string())),
+            ("prep", Unmeasured::Skipped),
+            ("part 1", Unmeasured::Unimplemented),
+            ("part 2", Unmeasured::Skipped)
+        ]
+    );
+
+    assert!(matches!(
+        bench_day(&STUB, &[], "invalid", 0, 1),
+        Err(RunError::Parse(_))
+    ));
+}
@@ -0,0 +1,113 @@
+use std::cmp::Ordering as CmpOrdering;
+use std::collections::BinaryHeap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex, Once, Weak};
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[cfg(test)]
+mod tests;
+
+/// Token telling a long-running solution to give up, either because it was cancelled or because its timeout
+/// elapsed. The default token is never cancelled. Clones share the same cancellation.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Token cancelled once `timeout` elapsed from now.
+    /// The token is cancelled by a watchdog thread shared by every token, so that checking it stays a single atomic
+    /// read. A timeout too long to be represented never elapses.
+    pub fn with_timeout(timeout: Duration) -> Self {
+        let token = Self::default();
+        if let Some(at) = Instant::now().checked_add(timeout) {
+            WATCHDOG.watch(Deadline {
+                at,
+                cancelled: Arc::downgrade(&token.cancelled),
+            });
+        }
+
+        token
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Check if the solution must give up, meant to be called regularly in long-running loops
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// Instant at which a token must be cancelled, nothing is cancelled if every clone of the token was dropped
+struct Deadline {
+    at: Instant,
+    cancelled: Weak<AtomicBool>,
+}
+
+// Deadlines are ordered from the latest to the earliest, so that the earliest one is at the top of the heap
+impl Ord for Deadline {
+    fn cmp(&self, other: &Self) -> CmpOrdering {
+        other.at.cmp(&self.at)
+    }
+}
+
+impl PartialOrd for Deadline {
+    fn partial_cmp(&self, other: &Self) -> Option<CmpOrdering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Deadline {
+    fn eq(&self, other: &Self) -> bool {
+        self.at == other.at
+    }
+}
+
+impl Eq for Deadline {}
+
+/// Single thread cancelling the tokens whose deadline elapsed, started with the first token having a timeout
+struct Watchdog {
+    deadlines: Mutex<BinaryHeap<Deadline>>,
+    /// Notified when a deadline is added, since it may be earlier than the one the thread waits for
+    added: Condvar,
+    started: Once,
+}
+
+static WATCHDOG: Watchdog = Watchdog {
+    deadlines: Mutex::new(BinaryHeap::new()),
+    added: Condvar::new(),
+    started: Once::new(),
+};
+
+impl Watchdog {
+    fn watch(&'static self, deadline: Deadline) {
+        self.deadlines.lock().unwrap().push(deadline);
+        self.added.notify_one();
+        self.started.call_once(|| {
+            thread::spawn(|| self.run());
+        });
+    }
+
+    fn run(&self) {
+        let mut deadlines = self.deadlines.lock().unwrap();
+        loop {
+            let now = Instant::now();
+            while deadlines.peek().is_some_and(|deadline| deadline.at <= now) {
+                if let Some(cancelled) = deadlines.pop().unwrap().cancelled.upgrade() {
+                    cancelled.store(true, Ordering::Relaxed);
+                }
+            }
+
+            deadlines = match deadlines.peek() {
+                Some(next) => {
+                    let wait = next.at - now;
+                    self.added.wait_timeout(deadlines, wait).unwrap().0
+                }
+                None => self.added.wait(deadlines).unwrap(),
+            };
+        }
+    }
+}
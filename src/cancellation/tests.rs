@@ -0,0 +1,83 @@
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::cancellation::CancellationToken;
+
+/// Wait until the token is cancelled, panicking if it takes more than a few seconds
+fn wait_cancelled(token: &CancellationToken) {
+    let start = Instant::now();
+    while !token.is_cancelled() {
+        assert!(start.elapsed() < Duration::from_secs(5), "the token was not cancelled");
+        thread::sleep(Duration::from_millis(1));
+    }
+}
+
+#[test]
+fn test_cancel() {
+    let token = CancellationToken::default();
+    let clone = token.clone();
+    assert!(!token.is_cancelled());
+    clone.cancel();
+    assert!(token.is_cancelled());
+}
+
+#[test]
+fn test_deadlines_out_of_order() {
+    // The watchdog waits for the earliest deadline, even when it is added after a later one
+    let timeouts = [300, 100, 200];
+    let tokens = timeouts.map(|timeout| CancellationToken::with_timeout(Duration::from_millis(timeout)));
+    assert!(tokens.iter().all(|token| !token.is_cancelled()));
+
+    let mut order = vec![];
+    let start = Instant::now();
+    while order.len() < tokens.len() {
+        assert!(
+            start.elapsed() < Duration::from_secs(5),
+            "the tokens were not cancelled"
+        );
+        for (i_token, token) in tokens.iter().enumerate() {
+            if token.is_cancelled() && !order.contains(&i_token) {
+                order.push(i_token);
+            }
+        }
+        thread::sleep(Duration::from_millis(1));
+    }
+    assert_eq!(order, vec![1, 2, 0]);
+    assert!(start.elapsed() >= Duration::from_millis(200));
+}
+
+#[test]
+fn test_dropped_token() {
+    // Nothing is kept alive for a dropped token, and the watchdog keeps cancelling the other ones
+    let token = CancellationToken::with_timeout(Duration::from_millis(50));
+    let cancelled = Arc::downgrade(&token.cancelled);
+    drop(token);
+    assert!(cancelled.upgrade().is_none());
+
+    let token = CancellationToken::with_timeout(Duration::from_millis(100));
+    wait_cancelled(&token);
+}
+
+#[test]
+fn test_never_elapsing_timeout() {
+    let token = CancellationToken::with_timeout(Duration::MAX);
+    thread::sleep(Duration::from_millis(50));
+    assert!(!token.is_cancelled());
+}
+
+#[test]
+fn test_cancelled_from_another_thread() {
+    // A solution running on another thread sees the cancellation
+    let token = CancellationToken::default();
+    let clone = token.clone();
+    let solver = thread::spawn(move || wait_cancelled(&clone));
+    thread::sleep(Duration::from_millis(20));
+    token.cancel();
+    solver.join().unwrap();
+
+    // And so does the watchdog
+    let token = CancellationToken::with_timeout(Duration::from_millis(50));
+    let solver = thread::spawn(move || wait_cancelled(&token));
+    solver.join().unwrap();
+}
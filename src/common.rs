@@ -1,13 +1,127 @@
-use crate::models::AdventSolution;
+use std::fs;
+use std::path::Path;
 
-pub fn test_part_one_common<S: AdventSolution>(mut solution: S, data: &str, expected_result: i128) {
-    solution.parse(data.to_string());
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::examples::{manifest_path, Example, Manifest};
+use crate::models::{AdventSolution, Answer};
+use crate::registry::Registration;
+
+pub fn test_part_one_common<S: AdventSolution>(mut solution: S, data: &str, expected_result: impl Into<Answer>) {
+    solution.parse(data.to_string()).unwrap();
     solution.prepare();
-    assert_eq!(solution.solve_part_one(), expected_result);
+    assert_eq!(solution.solve_part_one(), expected_result.into());
 }
 
-pub fn test_part_two_common<S: AdventSolution>(mut solution: S, data: &str, expected_result: i128) {
-    solution.parse(data.to_string());
+pub fn test_part_two_common<S: AdventSolution>(mut solution: S, data: &str, expected_result: impl Into<Answer>) {
+    solution.parse(data.to_string()).unwrap();
     solution.prepare();
-    assert_eq!(solution.solve_part_two(), expected_result);
+    assert_eq!(solution.solve_part_two(), expected_result.into());
+}
+
+/// Check that a solution gives the expected answers on every example of its day in the manifest of its year.
+/// Every day must have at least one known example answer.
+pub fn test_examples_common(registration: &Registration) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let year = registration.metadata.year;
+    let manifest = Manifest::load(&root.join(manifest_path(year)), year).unwrap();
+
+    test_manifest_examples_common(registration, &manifest, |example| {
+        fs::read_to_string(root.join(example.path())).unwrap()
+    });
+}
+
+/// Check that a solution gives the expected answers on every example of its day in a manifest, `read_input` gives the
+/// input of an example. The day must have at least one known example answer.
+pub fn test_manifest_examples_common(
+    registration: &Registration,
+    manifest: &Manifest,
+    read_input: impl Fn(&Example) -> String,
+) {
+    let day = registration.metadata.day;
+    let examples: Vec<_> = manifest.examples(day).collect();
+    assert!(
+        examples
+            .iter()
+            .any(|example| example.answers.iter().any(Option::is_some)),
+        "day {day} has no known example answer in the manifest"
+    );
+    for example in examples {
+        let mut solution = (registration.new_solution)();
+        for parameter in &example.parameters {
+            solution.configure(&parameter.name, &parameter.value).unwrap();
+        }
+        solution.parse(read_input(example)).unwrap();
+        solution.prepare();
+
+        let [part_one, part_two] = &example.answers;
+        if let Some(expected_result) = part_one {
+            assert_eq!(
+                &solution.solve_part_one().to_string(),
+                expected_result,
+                "part one of {}",
+                example.label()
+            );
+        }
+        if let Some(expected_result) = part_two {
+            assert_eq!(
+                &solution.solve_part_two().to_string(),
+                expected_result,
+                "part two of {}",
+                example.label()
+            );
+        }
+    }
+}
+
+/// Check that the inputs generated by a solution, for a few sizes and seeds, are accepted by its parser
+pub fn test_generated_input_common<S: AdventSolution + Default>() {
+    for size in [1, 5, 20] {
+        for seed in 0..5 {
+            let input = S::default()
+                .generate_input(size, &mut StdRng::seed_from_u64(seed))
+                .expect("the solution does not have an input generator");
+            if let Err(error) = S::default().parse(input.clone()) {
+                panic!("generated input with size {size} and seed {seed} does not parse: {error}\n{input}");
+            }
+        }
+    }
+}
+
+/// Check that a solution gives the same answers as its reference implementations on small generated inputs.
+/// `configure` is called once the input is parsed, to adapt the parameters of the solution to it for example.
+pub fn test_against_reference_common<S: AdventSolution + Default>(sizes: &[usize], configure: impl Fn(&mut S)) {
+    for &size in sizes {
+        for seed in 0..20 {
+            let mut solution = S::default();
+            let input = solution
+                .generate_input(size, &mut StdRng::seed_from_u64(seed))
+                .expect("the solution does not have an input generator");
+            solution.parse(input.clone()).unwrap();
+            configure(&mut solution);
+            solution.prepare();
+
+            let part_one = solution.solve_part_one_reference();
+            let part_two = solution.solve_part_two_reference();
+            assert!(
+                part_one.is_some() || part_two.is_some(),
+                "the solution does not have a reference implementation"
+            );
+            if let Some(expected_result) = part_one {
+                assert_eq!(
+                    solution.solve_part_one(),
+                    expected_result,
+                    "part one differs with size {size} and seed {seed} on:\n{input}"
+                );
+            }
+            if let Some(expected_result) = part_two {
+                assert_eq!(
+                    solution.solve_part_two(),
+                    expected_result,
+                    "part two differs with size {size} and seed {seed} on:\n{input}"
+                );
+            }
+        }
+    }
 }
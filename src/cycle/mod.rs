@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[cfg(test)]
+mod tests;
+
+/// Cycle reached by a state simulation: after `tail` steps, the states repeat every `length` steps.
+/// The step functions must be deterministic and the simulations must end up cycling, otherwise the
+/// detection never returns.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub struct Cycle {
+    pub tail: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// Smallest step reaching the same state as `step`
+    pub fn reduce(&self, step: usize) -> usize {
+        if step < self.tail {
+            step
+        } else {
+            self.tail + (step - self.tail) % self.length
+        }
+    }
+}
+
+/// Find the cycle with Floyd's tortoise and hare algorithm, taken from
+/// https://en.wikipedia.org/wiki/Cycle_detection#Floyd's_tortoise_and_hare.
+/// Only two states are kept in memory, at the cost of about three times as many steps as `find_cycle`.
+pub fn floyd<T: Eq>(initial: T, mut step: impl FnMut(&T) -> T) -> Cycle {
+    // Find a step multiple of the cycle length, where the hare is twice as far as the tortoise
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        hare = step(&hare);
+    }
+
+    // Both moving at the same speed, they meet at the start of the cycle
+    let mut tail = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        tail += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    Cycle { tail, length }
+}
+
+/// Find the cycle with Brent's algorithm, taken from https://en.wikipedia.org/wiki/Cycle_detection#Brent's_algorithm.
+/// Only two states are kept in memory, and fewer steps are needed than with `floyd`.
+pub fn brent<T: Clone + Eq>(initial: T, mut step: impl FnMut(&T) -> T) -> Cycle {
+    // Search the length with a tortoise teleported to the hare at every power of two
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // With the hare `length` steps ahead, they meet at the start of the cycle
+    let mut tail = 0;
+    tortoise = initial.clone();
+    hare = (0..length).fold(initial, |state, _| step(&state));
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        tail += 1;
+    }
+
+    Cycle { tail, length }
+}
+
+/// Simulate until a state repeats or `last` steps are made, returning the visited states and the cycle if found.
+/// Full states are stored rather than their hashes, so that a hash collision can not be taken for a cycle.
+fn simulate<T: Clone + Eq + Hash>(
+    initial: T,
+    mut step: impl FnMut(&T) -> T,
+    last: Option<usize>,
+) -> (Vec<T>, Option<Cycle>) {
+    let mut seen = HashMap::new();
+    let mut states = vec![];
+    let mut state = initial;
+    loop {
+        if let Some(tail) = seen.get(&state) {
+            let cycle = Cycle {
+                tail: *tail,
+                length: states.len() - tail,
+            };
+            return (states, Some(cycle));
+        }
+
+        seen.insert(state.clone(), states.len());
+        states.push(state);
+        if last.is_some_and(|last| states.len() > last) {
+            return (states, None);
+        }
+        state = step(&states[states.len() - 1]);
+    }
+}
+
+/// Find the cycle by storing every visited state in a hash map, which makes the least steps
+pub fn find_cycle<T: Clone + Eq + Hash>(initial: T, step: impl FnMut(&T) -> T) -> Cycle {
+    simulate(initial, step, None).1.unwrap()
+}
+
+/// State after `steps` steps, skipping the repetitions once a cycle is found
+pub fn state_at<T: Clone + Eq + Hash>(initial: T, step: impl FnMut(&T) -> T, steps: usize) -> T {
+    let (mut states, cycle) = simulate(initial, step, Some(steps));
+    let index = cycle.map_or(steps, |cycle| cycle.reduce(steps));
+    states.swap_remove(index)
+}
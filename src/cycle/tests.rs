@@ -0,0 +1,67 @@
+use std::hash::{Hash, Hasher};
+
+use crate::cycle::{brent, find_cycle, floyd, state_at, Cycle};
+
+/// Cycle of the sequence `x -> (x * x + increment) % modulus` starting at `initial`, found by looking
+/// for the first repeated value
+fn cycle_bruteforce(initial: u64, increment: u64, modulus: u64) -> Cycle {
+    let mut values = vec![initial];
+    loop {
+        let next = (values[values.len() - 1].pow(2) + increment) % modulus;
+        if let Some(tail) = values.iter().position(|value| *value == next) {
+            return Cycle {
+                tail,
+                length: values.len() - tail,
+            };
+        }
+        values.push(next);
+    }
+}
+
+#[test]
+fn test_cycle_detection() {
+    for modulus in 1..40 {
+        for increment in 0..modulus {
+            for initial in 0..modulus {
+                let step = |x: &u64| (x * x + increment) % modulus;
+                let expected = cycle_bruteforce(initial, increment, modulus);
+                let context = format!("x -> x * x + {increment} mod {modulus} from {initial}");
+                assert_eq!(floyd(initial, step), expected, "floyd for {context}");
+                assert_eq!(brent(initial, step), expected, "brent for {context}");
+                assert_eq!(find_cycle(initial, step), expected, "hash map for {context}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_state_at() {
+    let step = |x: &u64| (x * x + 1) % 1000;
+    let mut state = 3;
+    for steps in 0..200 {
+        assert_eq!(state_at(3, step, steps), state, "state after {steps} steps");
+        state = step(&state);
+    }
+
+    let cycle = find_cycle(3, step);
+    let reduced = cycle.reduce(1_000_000_000);
+    assert!(reduced < cycle.tail + cycle.length);
+    assert_eq!(state_at(3, step, 1_000_000_000), state_at(3, step, reduced));
+}
+
+/// State whose hash is always the same, so that every state collides
+#[derive(Debug, Eq, PartialEq, Clone)]
+struct Colliding(u64);
+
+impl Hash for Colliding {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        0.hash(state);
+    }
+}
+
+#[test]
+fn test_hash_collisions() {
+    let step = |x: &Colliding| Colliding((x.0 + 1) % 10);
+    assert_eq!(find_cycle(Colliding(0), step), Cycle { tail: 0, length: 10 });
+    assert_eq!(state_at(Colliding(0), step, 1234), Colliding(4));
+}
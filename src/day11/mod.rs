@@ -1,34 +0,0 @@
-mod logic;
-mod models;
-mod parser;
-
-#[cfg(test)]
-mod tests;
-
-use crate::day11::logic::{prepare, solve_part_one, solve_part_two};
-use crate::day11::models::{SkyMap, SparseSkyMap};
-use crate::day11::parser::parse_input;
-use crate::models::AdventSolution;
-
-#[derive(Default)]
-pub struct Day11 {
-    parsed_data: Option<SkyMap>,
-    prepared_data: Option<SparseSkyMap>,
-}
-
-impl AdventSolution for Day11 {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
-    }
-
-    fn prepare(&mut self) {
-        self.prepared_data = Some(prepare(self.parsed_data.as_ref().unwrap()));
-    }
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.prepared_data.as_ref().unwrap()) as i128
-    }
-
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.prepared_data.as_ref().unwrap()) as i128
-    }
-}
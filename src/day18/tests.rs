@@ -1,28 +0,0 @@
-use crate::common::{test_part_one_common, test_part_two_common};
-use crate::day18::Day18;
-
-static INPUT_EXAMPLE: &str = "R 6 (#70c710)
-D 5 (#0dc571)
-L 2 (#5713f0)
-D 2 (#d2c081)
-R 2 (#59c680)
-D 2 (#411b91)
-L 5 (#8ceee2)
-U 2 (#caa173)
-L 1 (#1b58a2)
-U 2 (#caa171)
-R 2 (#7807d2)
-U 3 (#a77fa3)
-L 2 (#015232)
-U 2 (#7a21e3)
-";
-
-#[test]
-fn test_part_one() {
-    test_part_one_common(Day18::default(), INPUT_EXAMPLE, 62);
-}
-
-#[test]
-fn test_part_two() {
-    test_part_two_common(Day18::default(), INPUT_EXAMPLE, 952408144115);
-}
@@ -1,3 +1,4 @@
+mod generator;
 mod logic;
 mod models;
 mod parser;
@@ -5,9 +6,16 @@ mod parser;
 #[cfg(test)]
 mod tests;
 
+use rand::rngs::StdRng;
+
+use crate::dayxx::generator::generate_input;
 use crate::dayxx::logic::{solve_part_one, solve_part_two};
 use crate::dayxx::parser::parse_input;
-use crate::models::AdventSolution;
+use crate::models::{AdventSolution, Answer};
+use crate::parser::ParseError;
+use crate::registry::Registration;
+
+pub const REGISTRATION: Registration = Registration::new::<DayXX>(2023, 0, "Title", [true, true]);
 
 #[derive(Default)]
 pub struct DayXX {
@@ -15,15 +23,20 @@ pub struct DayXX {
 }
 
 impl AdventSolution for DayXX {
-    fn parse(&mut self, _data: String) {
-        self.parsed_data = Some(parse_input(_data));
+    fn parse(&mut self, _data: String) -> Result<(), ParseError> {
+        self.parsed_data = Some(parse_input(_data)?);
+        Ok(())
+    }
+
+    fn solve_part_one(&self) -> Answer {
+        solve_part_one(self.parsed_data.as_ref().unwrap()).into()
     }
 
-    fn solve_part_one(&self) -> i128 {
-        solve_part_one(self.parsed_data.as_ref().unwrap()) as i128
+    fn solve_part_two(&self) -> Answer {
+        solve_part_two(self.parsed_data.as_ref().unwrap()).into()
     }
 
-    fn solve_part_two(&self) -> i128 {
-        solve_part_two(self.parsed_data.as_ref().unwrap()) as i128
+    fn generate_input(&self, size: usize, rng: &mut StdRng) -> Option<String> {
+        Some(generate_input(size, rng))
     }
 }
@@ -1,14 +1,15 @@
-use crate::common::{test_part_one_common, test_part_two_common};
-use crate::dayxx::DayXX;
-
-static INPUT_EXAMPLE: &str = "";
+use crate::common::{test_generated_input_common, test_manifest_examples_common};
+use crate::dayxx::{DayXX, REGISTRATION};
+use crate::examples::Manifest;
 
 #[test]
-fn test_part_one() {
-    test_part_one_common(DayXX::default(), INPUT_EXAMPLE, 0);
+fn test_examples() {
+    // The template is not a day of its year, its empty example is described by a manifest of its own
+    let manifest = Manifest::parse("0 1 empty 0\n0 2 empty 0\n", REGISTRATION.metadata.year).unwrap();
+    test_manifest_examples_common(&REGISTRATION, &manifest, |_| String::new());
 }
 
 #[test]
-fn test_part_two() {
-    test_part_two_common(DayXX::default(), INPUT_EXAMPLE, 0);
+fn test_generated_input() {
+    test_generated_input_common::<DayXX>();
 }
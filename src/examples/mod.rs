@@ -0,0 +1,129 @@
+use std::fs;
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+
+use crate::parameters::ParameterAssignment;
+
+#[cfg(test)]
+mod tests;
+
+/// Directory storing the example inputs of every year, with a manifest per year
+pub const EXAMPLES_DIRECTORY: &str = "input_examples";
+
+/// Answer of the manifest marking an answer that is not known yet, the example does not apply to the part until the
+/// answer is filled in
+pub const UNKNOWN_ANSWER: &str = "?";
+
+/// Path of the manifest of the example inputs of a year, relative to the crate root
+pub fn manifest_path(year: usize) -> String {
+    format!("{EXAMPLES_DIRECTORY}/{year}/manifest.txt")
+}
+
+/// Example input of a day, with the parameters it is solved with and the expected answers
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Example {
+    pub year: usize,
+    pub day: usize,
+    /// Name of the file in the examples directory of the year
+    pub file: String,
+    pub parameters: Vec<ParameterAssignment>,
+    /// Expected answers of part one and two, `None` if the example does not apply to the part
+    pub answers: [Option<String>; 2],
+}
+
+impl Example {
+    /// Path of the input file, relative to the crate root
+    pub fn path(&self) -> String {
+        format!("{EXAMPLES_DIRECTORY}/{}/{}", self.year, self.file)
+    }
+
+    /// Path of the input file followed by the parameters, identifying the example
+    pub fn label(&self) -> String {
+        let mut label = self.path();
+        for parameter in &self.parameters {
+            label.push_str(&format!(" {parameter}"));
+        }
+
+        label
+    }
+}
+
+/// Examples of every day of a year.
+///
+/// The file format is line based, every line has the form
+/// `<day> <part> <example file> <answer> [<parameter>=<value>...]`.
+/// The lines sharing the same day, file and parameters describe the same example. An answer of `UNKNOWN_ANSWER`
+/// declares the example without an expected answer for the part.
+/// Empty lines and lines starting with `#` are ignored.
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct Manifest {
+    examples: Vec<Example>,
+}
+
+impl Manifest {
+    pub fn load(path: &Path, year: usize) -> Result<Self, Error> {
+        Self::parse(&fs::read_to_string(path)?, year)
+    }
+
+    pub fn parse(content: &str, year: usize) -> Result<Self, Error> {
+        let mut examples: Vec<Example> = vec![];
+        // Parts declared for each example, their answer may be unknown
+        let mut declared: Vec<[bool; 2]> = vec![];
+
+        for (i_line, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = |message: &str| Error::new(ErrorKind::InvalidData, format!("line {}: {message}", i_line + 1));
+            let fields: Vec<_> = line.split_whitespace().collect();
+            let (day, part, file, answer, parameters) = match fields[..] {
+                [day, part, file, answer, ref parameters @ ..] => (
+                    day.parse::<usize>().ok(),
+                    part.parse::<usize>().ok().filter(|part| (1..=2).contains(part)),
+                    file,
+                    answer,
+                    parameters
+                        .iter()
+                        .map(|parameter| parameter.parse::<ParameterAssignment>())
+                        .collect::<Result<Vec<_>, _>>(),
+                ),
+                _ => (None, None, "", "", Ok(vec![])),
+            };
+            let (Some(day), Some(part), Ok(parameters)) = (day, part, parameters) else {
+                return Err(invalid(
+                    "expected `<day> <part> <example file> <answer> [<parameter>=<value>...]`",
+                ));
+            };
+
+            let position = examples
+                .iter()
+                .position(|example| example.day == day && example.file == file && example.parameters == parameters)
+                .unwrap_or_else(|| {
+                    examples.push(Example {
+                        year,
+                        day,
+                        file: file.to_string(),
+                        parameters,
+                        answers: [None, None],
+                    });
+                    declared.push([false, false]);
+                    examples.len() - 1
+                });
+            if std::mem::replace(&mut declared[position][part - 1], true) {
+                return Err(invalid(&format!("duplicate answer of part {part} for {file}")));
+            }
+            if answer != UNKNOWN_ANSWER {
+                examples[position].answers[part - 1] = Some(answer.to_string());
+            }
+        }
+
+        Ok(Self { examples })
+    }
+
+    /// Examples of a day, in the order of the manifest
+    pub fn examples(&self, day: usize) -> impl Iterator<Item = &Example> {
+        self.examples.iter().filter(move |example| example.day == day)
+    }
+}
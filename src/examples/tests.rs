@@ -0,0 +1,99 @@
+use std::io::ErrorKind;
+
+use crate::examples::{Example, Manifest};
+use crate::parameters::ParameterAssignment;
+
+fn parameter(name: &str, value: &str) -> ParameterAssignment {
+    ParameterAssignment {
+        name: name.to_string(),
+        value: value.to_string(),
+    }
+}
+
+#[test]
+fn test_parse() {
+    let manifest = Manifest::parse(
+        "# <day> <part> <example file> <answer> [<parameter>=<value>...]\n\
+        \n\
+        21 1 day21 16 part_one_steps=6\n\
+        21 2 day21 16 part_two_steps=6\n\
+        21 2 day21 50  part_two_steps=10\n\
+        3 2 day03 467835\n\
+        3 1 day03 4361\n",
+        2023,
+    )
+    .unwrap();
+
+    let examples: Vec<_> = manifest.examples(21).collect();
+    assert_eq!(
+        examples,
+        [
+            &Example {
+                year: 2023,
+                day: 21,
+                file: "day21".to_string(),
+                parameters: vec![parameter("part_one_steps", "6")],
+                answers: [Some("16".to_string()), None],
+            },
+            &Example {
+                year: 2023,
+                day: 21,
+                file: "day21".to_string(),
+                parameters: vec![parameter("part_two_steps", "6")],
+                answers: [None, Some("16".to_string())],
+            },
+            &Example {
+                year: 2023,
+                day: 21,
+                file: "day21".to_string(),
+                parameters: vec![parameter("part_two_steps", "10")],
+                answers: [None, Some("50".to_string())],
+            },
+        ]
+    );
+    assert_eq!(examples[2].label(), "input_examples/2023/day21 part_two_steps=10");
+
+    // The lines of the two parts of the same file describe a single example
+    let examples: Vec<_> = manifest.examples(3).collect();
+    assert_eq!(examples.len(), 1);
+    assert_eq!(
+        examples[0].answers,
+        [Some("4361".to_string()), Some("467835".to_string())]
+    );
+    assert_eq!(examples[0].path(), "input_examples/2023/day03");
+    assert_eq!(manifest.examples(4).count(), 0);
+}
+
+#[test]
+fn test_parse_unknown_answer() {
+    let manifest = Manifest::parse("4 1 day04 ?\n4 2 day04 30\n5 1 day05 ?\n5 2 day05 ?\n", 2023).unwrap();
+    let answers: Vec<_> = [4, 5]
+        .into_iter()
+        .flat_map(|day| manifest.examples(day))
+        .map(|example| example.answers.clone())
+        .collect();
+    assert_eq!(answers, [[None, Some("30".to_string())], [None, None]]);
+}
+
+#[test]
+fn test_parse_malformed() {
+    for (content, message) in [
+        ("3 1 day03", "line 1: expected"),
+        ("x 1 day03 4361", "line 1: expected"),
+        ("3 3 day03 4361", "line 1: expected"),
+        ("# comment\n3 1 day03 4361 steps", "line 2: expected"),
+        ("3 1 day03 4361 =6", "line 1: expected"),
+        (
+            "3 1 day03 4361\n3 1 day03 4362",
+            "line 2: duplicate answer of part 1 for day03",
+        ),
+        (
+            "3 1 day03 ?\n3 1 day03 4361",
+            "line 2: duplicate answer of part 1 for day03",
+        ),
+    ] {
+        let error = Manifest::parse(content, 2023).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData, "{content}");
+        assert!(error.to_string().starts_with(message), "{content}: {error}");
+    }
+}
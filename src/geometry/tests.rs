@@ -0,0 +1,83 @@
+use crate::geometry::{Axis, Direction, Direction8, Point2, Point3, Turn};
+
+#[test]
+fn test_direction_turns() {
+    assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    assert_eq!(Direction::Left.turn(Turn::Right), Direction::Up);
+    assert_eq!(Direction::Down.turn(Turn::Left), Direction::Right);
+
+    for direction in Direction::ALL {
+        assert_eq!(direction.turn_right().turn_left(), direction);
+        assert_eq!(direction.turn_left().turn_right(), direction);
+        assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+        assert_eq!(direction.opposite().opposite(), direction);
+        assert_ne!(direction.opposite(), direction);
+
+        let (d_line, d_column) = direction.offset();
+        assert_eq!(direction.opposite().offset(), (-d_line, -d_column));
+        assert_eq!(Direction8::from(direction).offset(), direction.offset());
+        assert_eq!(
+            Direction::from_letter(direction.to_string().chars().next().unwrap()),
+            Some(direction)
+        );
+    }
+    assert_eq!(Direction::from_letter('N'), Some(Direction::Up));
+    assert_eq!(Direction::from_letter('W'), Some(Direction::Left));
+    assert_eq!(Direction::from_letter('X'), None);
+    assert_eq!(Turn::from_letter('L'), Some(Turn::Left));
+    assert_eq!(Turn::from_letter('U'), None);
+}
+
+#[test]
+fn test_direction8_turns() {
+    assert_eq!(Direction8::Up.turn_right(), Direction8::UpRight);
+    assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+
+    for direction in Direction8::ALL {
+        assert_eq!(direction.turn_right().turn_left(), direction);
+        assert_eq!(direction.opposite().opposite(), direction);
+        assert_eq!(
+            (0..4).fold(direction, |direction, _| direction.turn_right()),
+            direction.opposite()
+        );
+
+        let (d_line, d_column) = direction.offset();
+        assert_eq!(direction.opposite().offset(), (-d_line, -d_column));
+    }
+}
+
+#[test]
+fn test_point2() {
+    let point = Point2::new(3, -2);
+    assert_eq!(point + Point2::new(1, 5), Point2::new(4, 3));
+    assert_eq!(point - Point2::new(1, 5), Point2::new(2, -7));
+    assert_eq!(point.manhattan_distance(&Point2::new(-1, 1)), 7);
+    assert_eq!(point.manhattan_distance(&point), 0);
+    assert_eq!(point.to_string(), "(3, -2)");
+
+    // The y coordinate grows downwards
+    assert_eq!(point.moved(Direction::Up, 2), Point2::new(3, -4));
+    assert_eq!(point.moved(Direction::Right, 2), Point2::new(5, -2));
+    for direction in Direction::ALL {
+        assert_eq!(point.moved(direction, 4).moved(direction.opposite(), 4), point);
+    }
+
+    let point = Point2::from_position((2, 5));
+    assert_eq!(point, Point2::new(5, 2));
+    assert_eq!(point.position(), (2, 5));
+}
+
+#[test]
+fn test_point3() {
+    let point = Point3::new(1, -2, 3);
+    assert_eq!(point + Point3::new(1, 1, 1), Point3::new(2, -1, 4));
+    assert_eq!(point - Point3::new(1, 1, 1), Point3::new(0, -3, 2));
+    assert_eq!(point.manhattan_distance(&Point3::new(0, 0, 0)), 6);
+    assert_eq!(
+        [Axis::X, Axis::Y, Axis::Z].map(|axis| point.coordinate(axis)),
+        [1, -2, 3]
+    );
+    assert_eq!(point.to_string(), "(1, -2, 3)");
+    assert!(Point3::new(1, 5, 5) < Point3::new(2, 0, 0));
+}
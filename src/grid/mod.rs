@@ -0,0 +1,261 @@
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+#[cfg(test)]
+mod tests;
+
+/// Offsets of the 4 orthogonal neighbors of a cell, clockwise from the top
+pub const NEIGHBORS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+/// Offsets of the 8 neighbors of a cell including the diagonals, clockwise from the top left
+pub const NEIGHBORS_8: [(isize, isize); 8] = [(-1, -1), (-1, 0), (-1, 1), (0, 1), (1, 1), (1, 0), (1, -1), (0, -1)];
+
+/// Rectangular grid of cells stored line by line.
+/// Cells are addressed by `(line, column)` positions, starting at the top left corner.
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Create a grid from its cells, line by line
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "a {width}x{height} grid needs {} cells",
+            width * height
+        );
+        Self { cells, width, height }
+    }
+
+    /// Create a grid from its lines, that must all have the same length
+    pub fn from_lines(lines: Vec<Vec<T>>) -> Self {
+        let width = lines.first().map_or(0, |line| line.len());
+        let height = lines.len();
+        assert!(
+            lines.iter().all(|line| line.len() == width),
+            "the lines of a grid must have the same length"
+        );
+
+        Self::new(width, height, lines.into_iter().flatten().collect())
+    }
+
+    /// Create a grid by computing the cell of every position
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut((usize, usize)) -> T) -> Self {
+        Self::new(
+            width,
+            height,
+            (0..width * height)
+                .map(|offset| cell((offset / width, offset % width)))
+                .collect(),
+        )
+    }
+
+    /// Parse a grid with one cell per character, the input must be rectangular as checked by `check_rectangular`
+    pub fn parse_with(input: &str, mut cell: impl FnMut(char) -> T) -> Self {
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        let height = input.lines().count();
+
+        Self::new(
+            width,
+            height,
+            input.lines().flat_map(|line| line.chars()).map(&mut cell).collect(),
+        )
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Offset of a position in the cells
+    #[inline(always)]
+    pub fn offset(&self, (line, column): (usize, usize)) -> usize {
+        line * self.width + column
+    }
+
+    /// Position of an offset in the cells
+    #[inline(always)]
+    pub fn position(&self, offset: usize) -> (usize, usize) {
+        (offset / self.width, offset % self.width)
+    }
+
+    pub fn contains(&self, (line, column): (usize, usize)) -> bool {
+        line < self.height && column < self.width
+    }
+
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        self.contains(position).then(|| &self.cells[self.offset(position)])
+    }
+
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        if self.contains(position) {
+            let offset = self.offset(position);
+            Some(&mut self.cells[offset])
+        } else {
+            None
+        }
+    }
+
+    /// Cells of the grid, line by line
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn cells_mut(&mut self) -> &mut [T] {
+        &mut self.cells
+    }
+
+    /// Every cell with its position, line by line
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(offset, cell)| ((offset / width, offset % width), cell))
+    }
+
+    /// Position of the first cell matching the predicate, line by line
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|offset| self.position(offset))
+    }
+
+    /// Position at the given offset of a position, `None` if it is outside of the grid
+    pub fn neighbor(
+        &self,
+        (line, column): (usize, usize),
+        (d_line, d_column): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let neighbor = (line.checked_add_signed(d_line)?, column.checked_add_signed(d_column)?);
+        self.contains(neighbor).then_some(neighbor)
+    }
+
+    /// Orthogonal neighbors of a position that are inside the grid, clockwise from the top
+    pub fn neighbors4(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBORS_4
+            .into_iter()
+            .filter_map(move |offset| self.neighbor(position, offset))
+    }
+
+    /// Neighbors of a position including the diagonals that are inside the grid, clockwise from the top left
+    pub fn neighbors8(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBORS_8
+            .into_iter()
+            .filter_map(move |offset| self.neighbor(position, offset))
+    }
+
+    pub fn line(&self, line: usize) -> &[T] {
+        &self.cells[line * self.width..(line + 1) * self.width]
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` does not accept a width of 0
+        (0..self.height).map(|line| self.line(line))
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            column < self.width,
+            "column {column} is outside of a grid of width {}",
+            self.width
+        );
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    /// Grid with the cells transformed by a function
+    pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(cell).collect())
+    }
+
+    /// Render the grid line by line, every cell being rendered by a function of its position and content
+    pub fn render(&self, mut render_cell: impl FnMut((usize, usize), &T) -> String) -> String {
+        let mut rendered = String::new();
+        for (position, cell) in self.iter() {
+            rendered.push_str(&render_cell(position, cell));
+            if position.1 + 1 == self.width {
+                rendered.push('\n');
+            }
+        }
+
+        rendered
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Create a grid where every cell has the same value
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Grid with the lines as columns
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(line, column)| self[(column, line)].clone())
+    }
+
+    /// Grid rotated by a quarter turn, the left side becoming the top side
+    pub fn rotate_clockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(line, column)| {
+            self[(self.height - column - 1, line)].clone()
+        })
+    }
+
+    /// Grid rotated by a quarter turn, the right side becoming the top side
+    pub fn rotate_counterclockwise(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(line, column)| {
+            self[(column, self.width - line - 1)].clone()
+        })
+    }
+}
+
+impl<T: From<char>> Grid<T> {
+    /// Parse a grid with one cell per character, the input must be rectangular as checked by `check_rectangular`
+    pub fn parse(input: &str) -> Self {
+        Self::parse_with(input, From::from)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    #[inline(always)]
+    fn index(&self, position: (usize, usize)) -> &Self::Output {
+        // Without the check, a column past the width would silently address a cell of the next line
+        assert!(self.contains(position), "{position:?} is outside of the grid");
+        &self.cells[self.offset(position)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    #[inline(always)]
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut Self::Output {
+        assert!(self.contains(position), "{position:?} is outside of the grid");
+        let offset = self.offset(position);
+        &mut self.cells[offset]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for line in self.lines() {
+            for cell in line {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use crate::grid::Grid;
+
+/// Grid of 3 columns and 2 lines
+fn grid() -> Grid<char> {
+    Grid::parse("abc\ndef\n")
+}
+
+#[test]
+fn test_parse() {
+    let grid = grid();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid.cells(), ['a', 'b', 'c', 'd', 'e', 'f']);
+    assert_eq!((grid[(0, 2)], grid[(1, 0)]), ('c', 'd'));
+    assert_eq!(grid.to_string(), "abc\ndef\n");
+
+    let walls = Grid::parse_with(".#\n#.\n##", |cell| cell == '#');
+    assert_eq!((walls.width(), walls.height()), (2, 3));
+    assert_eq!(walls.cells(), [false, true, true, false, true, true]);
+}
+
+#[test]
+fn test_transpose() {
+    let transposed = grid().transpose();
+    assert_eq!(transposed.to_string(), "ad\nbe\ncf\n");
+    assert_eq!(transposed.transpose(), grid());
+}
+
+#[test]
+fn test_rotate() {
+    assert_eq!(grid().rotate_clockwise().to_string(), "da\neb\nfc\n");
+    assert_eq!(grid().rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+    assert_eq!(grid().rotate_clockwise().rotate_counterclockwise(), grid());
+
+    let rotated = (0..4).fold(grid(), |grid, _| grid.rotate_clockwise());
+    assert_eq!(rotated, grid());
+}
+
+#[test]
+fn test_neighbors() {
+    let grid = grid();
+    assert_eq!(grid.neighbor((1, 0), (-1, 1)), Some((0, 1)));
+    assert_eq!(grid.neighbor((0, 2), (0, 1)), None);
+    assert_eq!(grid.neighbor((0, 0), (-1, 0)), None);
+    assert_eq!(grid.neighbor((1, 2), (1, 0)), None);
+
+    assert_eq!(grid.neighbors4((0, 1)).collect::<Vec<_>>(), [(0, 2), (1, 1), (0, 0)]);
+    assert_eq!(grid.neighbors4((1, 2)).collect::<Vec<_>>(), [(0, 2), (1, 1)]);
+    assert_eq!(
+        grid.neighbors8((1, 1)).collect::<Vec<_>>(),
+        [(0, 0), (0, 1), (0, 2), (1, 2), (1, 0)]
+    );
+}
+
+#[test]
+#[should_panic(expected = "(0, 3) is outside of the grid")]
+fn test_index_outside() {
+    // The offset of this position is the one of (1, 0)
+    let _ = grid()[(0, 3)];
+}
+
+#[test]
+#[should_panic(expected = "(2, 0) is outside of the grid")]
+fn test_index_mut_outside() {
+    grid()[(2, 0)] = 'g';
+}
@@ -0,0 +1,269 @@
+use std::fmt::{Display, Formatter};
+
+#[cfg(test)]
+mod tests;
+
+/// Half-open interval of integers `start..end`, empty when `end <= start`
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    /// Interval of `len` integers beginning at `start`
+    pub fn with_len(start: i64, len: i64) -> Self {
+        Self::new(start, start + len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// Number of integers in the interval
+    pub fn len(&self) -> i64 {
+        (self.end - self.start).max(0)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Common part of both intervals, `None` if they do not overlap
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let intersection = Self::new(self.start.max(other.start), self.end.min(other.end));
+        (!intersection.is_empty()).then_some(intersection)
+    }
+
+    /// Split the interval into the values lower than `value` and the values greater or equal to it,
+    /// a side being `None` when it is empty
+    pub fn split_at(&self, value: i64) -> (Option<Self>, Option<Self>) {
+        let lower = Self::new(self.start, self.end.min(value));
+        let upper = Self::new(self.start.max(value), self.end);
+        (
+            (!lower.is_empty()).then_some(lower),
+            (!upper.is_empty()).then_some(upper),
+        )
+    }
+
+    /// Interval translated by `offset`
+    pub fn shifted(&self, offset: i64) -> Self {
+        Self::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// Set of integers stored as sorted disjoint intervals.
+/// The intervals are normalized: none of them is empty and adjacent intervals are merged,
+/// so two sets holding the same integers are equal.
+#[derive(Debug, Eq, PartialEq, Clone, Hash, Default)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sort the intervals, drop the empty ones and merge the overlapping or adjacent ones
+    fn normalize(mut intervals: Vec<Interval>) -> Self {
+        intervals.retain(|interval| !interval.is_empty());
+        intervals.sort_unstable();
+
+        let mut normalized: Vec<Interval> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match normalized.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => normalized.push(interval),
+            }
+        }
+
+        Self { intervals: normalized }
+    }
+
+    /// Sorted disjoint intervals of the set
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Number of integers in the set
+    pub fn len(&self) -> i64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        // The intervals are sorted, so the candidate is the last one starting at or before the value
+        let index = self.intervals.partition_point(|interval| interval.start <= value);
+        index > 0 && self.intervals[index - 1].contains(value)
+    }
+
+    /// Smallest integer of the set
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|interval| interval.start)
+    }
+
+    /// Largest integer of the set
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|interval| interval.end - 1)
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        let mut intervals = std::mem::take(&mut self.intervals);
+        intervals.push(interval);
+        *self = Self::normalize(intervals);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        Self::normalize(self.intervals.iter().chain(&other.intervals).copied().collect())
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        // Both sides are sorted, walk them together advancing the one that ends first
+        let mut intervals = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a, b) = (self.intervals[i], other.intervals[j]);
+            intervals.extend(a.intersection(&b));
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        Self::normalize(intervals)
+    }
+
+    /// Integers of the set that are not in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let mut removed = other.intervals.iter().peekable();
+        for interval in &self.intervals {
+            let mut current = *interval;
+
+            // Skip the removed intervals that end before this one
+            while removed.next_if(|removed| removed.end <= current.start).is_some() {}
+
+            // Cut every removed interval overlapping this one, keeping what is before it
+            while let Some(cut) = removed.peek().filter(|cut| cut.start < current.end) {
+                intervals.push(Interval::new(current.start, cut.start));
+                current.start = current.start.max(cut.end);
+                if cut.end > current.end {
+                    // The removed interval may also overlap the next intervals of the set
+                    break;
+                }
+                removed.next();
+            }
+
+            intervals.push(current);
+        }
+
+        Self::normalize(intervals)
+    }
+
+    /// Split the set into the values lower than `value` and the values greater or equal to it
+    pub fn split_at(&self, value: i64) -> (Self, Self) {
+        let (lower, upper): (Vec<_>, Vec<_>) = self.intervals.iter().map(|interval| interval.split_at(value)).unzip();
+        (
+            Self::normalize(lower.into_iter().flatten().collect()),
+            Self::normalize(upper.into_iter().flatten().collect()),
+        )
+    }
+
+    /// Set translated by `offset`
+    pub fn shifted(&self, offset: i64) -> Self {
+        Self {
+            intervals: self.intervals.iter().map(|interval| interval.shifted(offset)).collect(),
+        }
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        Self::normalize(vec![interval])
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        Self::normalize(iter.into_iter().collect())
+    }
+}
+
+impl Display for IntervalSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{{")?;
+        for (i, interval) in self.intervals.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{interval}")?;
+        }
+        write!(f, "}}")
+    }
+}
+
+/// Box of `N` dimensions, the product of one interval per dimension
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub struct IntervalBox<const N: usize> {
+    pub intervals: [Interval; N],
+}
+
+impl<const N: usize> IntervalBox<N> {
+    pub fn new(intervals: [Interval; N]) -> Self {
+        Self { intervals }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.iter().any(Interval::is_empty)
+    }
+
+    /// Number of integer points in the box
+    pub fn volume(&self) -> i64 {
+        self.intervals.iter().map(Interval::len).product()
+    }
+
+    pub fn contains(&self, point: [i64; N]) -> bool {
+        self.intervals
+            .iter()
+            .zip(point)
+            .all(|(interval, value)| interval.contains(value))
+    }
+
+    /// Common part of both boxes, `None` if they do not overlap
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let mut intervals = self.intervals;
+        for (interval, other) in intervals.iter_mut().zip(&other.intervals) {
+            *interval = interval.intersection(other)?;
+        }
+
+        Some(Self::new(intervals))
+    }
+
+    /// Split the box along a dimension into the points lower than `value` on it and the points greater
+    /// or equal to it, a side being `None` when it is empty
+    pub fn split_at(&self, dimension: usize, value: i64) -> (Option<Self>, Option<Self>) {
+        let (lower, upper) = self.intervals[dimension].split_at(value);
+        let with_interval = |interval| {
+            let mut intervals = self.intervals;
+            intervals[dimension] = interval;
+            Self::new(intervals)
+        };
+
+        (lower.map(with_interval), upper.map(with_interval))
+    }
+}
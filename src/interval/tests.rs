@@ -0,0 +1,145 @@
+use std::collections::BTreeSet;
+
+use crate::interval::{Interval, IntervalBox, IntervalSet};
+
+/// Every interval with bounds in `-bound..=bound`, including empty ones
+fn small_intervals(bound: i64) -> impl Iterator<Item = Interval> {
+    (-bound..=bound).flat_map(move |start| (-bound..=bound).map(move |end| Interval::new(start, end)))
+}
+
+/// Small sets built from up to two intervals
+fn small_sets() -> Vec<IntervalSet> {
+    small_intervals(2)
+        .flat_map(|a| small_intervals(2).map(move |b| IntervalSet::from_iter([a, b])))
+        .collect()
+}
+
+fn values(set: &IntervalSet) -> BTreeSet<i64> {
+    set.intervals()
+        .iter()
+        .flat_map(|interval| interval.start..interval.end)
+        .collect()
+}
+
+fn assert_normalized(set: &IntervalSet) {
+    assert!(set.intervals().iter().all(|interval| !interval.is_empty()), "{set}");
+    assert!(set.intervals().windows(2).all(|w| w[0].end < w[1].start), "{set}");
+}
+
+#[test]
+fn test_interval() {
+    for a in small_intervals(3) {
+        let a_values: BTreeSet<_> = (a.start..a.end).collect();
+        assert_eq!(a.len(), a_values.len() as i64, "length of {a}");
+        assert_eq!(a.is_empty(), a_values.is_empty(), "emptiness of {a}");
+
+        for b in small_intervals(3) {
+            let b_values: BTreeSet<_> = (b.start..b.end).collect();
+            let expected: BTreeSet<_> = a_values.intersection(&b_values).copied().collect();
+            let intersection = a.intersection(&b);
+            assert_eq!(
+                intersection.is_none(),
+                expected.is_empty(),
+                "intersection of {a} and {b}"
+            );
+            if let Some(intersection) = intersection {
+                assert_eq!(
+                    (intersection.start..intersection.end).collect::<BTreeSet<_>>(),
+                    expected
+                );
+            }
+        }
+
+        for value in -4..=4 {
+            let (lower, upper) = a.split_at(value);
+            assert_eq!(
+                lower.map_or(0, |lower| lower.len()),
+                a_values.range(..value).count() as i64
+            );
+            assert_eq!(
+                upper.map_or(0, |upper| upper.len()),
+                a_values.range(value..).count() as i64
+            );
+        }
+    }
+}
+
+#[test]
+fn test_normalization() {
+    let set: IntervalSet = [
+        Interval::new(5, 7),
+        Interval::new(0, 2),
+        Interval::new(2, 3),
+        Interval::new(8, 8),
+        Interval::new(6, 10),
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!(set.intervals(), &[Interval::new(0, 3), Interval::new(5, 10)]);
+    assert_eq!(set.len(), 8);
+    assert_eq!((set.min(), set.max()), (Some(0), Some(9)));
+    assert_eq!(set.to_string(), "{0..3, 5..10}");
+
+    let (lower, upper) = set.split_at(6);
+    assert_eq!(lower.intervals(), &[Interval::new(0, 3), Interval::new(5, 6)]);
+    assert_eq!(upper.intervals(), &[Interval::new(6, 10)]);
+}
+
+#[test]
+fn test_set_operations() {
+    let sets = small_sets();
+    for a in &sets {
+        assert_normalized(a);
+        let a_values = values(a);
+        assert_eq!(a.len(), a_values.len() as i64);
+        assert!(
+            (-4..=4).all(|value| a.contains(value) == a_values.contains(&value)),
+            "{a}"
+        );
+
+        for b in &sets {
+            let b_values = values(b);
+            for (operation, result, expected) in [
+                ("union", a.union(b), a_values.union(&b_values).copied().collect()),
+                (
+                    "intersection",
+                    a.intersection(b),
+                    a_values.intersection(&b_values).copied().collect(),
+                ),
+                (
+                    "difference",
+                    a.difference(b),
+                    a_values.difference(&b_values).copied().collect::<BTreeSet<_>>(),
+                ),
+            ] {
+                assert_normalized(&result);
+                assert_eq!(values(&result), expected, "{operation} of {a} and {b}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_interval_box() {
+    let cube = IntervalBox::new([Interval::new(1, 5), Interval::new(0, 3), Interval::new(-2, 2)]);
+    assert_eq!(cube.volume(), 48);
+    assert!(cube.contains([4, 0, -2]));
+    assert!(!cube.contains([4, 3, -2]));
+
+    let (lower, upper) = cube.split_at(0, 2);
+    assert_eq!(lower.unwrap().volume() + upper.unwrap().volume(), cube.volume());
+    assert_eq!(cube.split_at(1, 0), (None, Some(cube)));
+    assert_eq!(cube.split_at(2, 10), (Some(cube), None));
+
+    let other = IntervalBox::new([Interval::new(3, 9), Interval::new(2, 9), Interval::new(1, 9)]);
+    assert_eq!(
+        cube.intersection(&other),
+        Some(IntervalBox::new([
+            Interval::new(3, 5),
+            Interval::new(2, 3),
+            Interval::new(1, 2)
+        ]))
+    );
+    assert_eq!(cube.intersection(&IntervalBox::new([Interval::new(5, 6); 3])), None);
+}
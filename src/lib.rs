@@ -1,29 +1,40 @@
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
+/// Register the solutions of the day modules of a year.
+/// Every listed module must expose a `REGISTRATION` constant.
+macro_rules! register_days {
+    ($($day:ident),* $(,)?) => {
+        pub static SOLUTIONS: &[crate::registry::Registration] = &[$($day::REGISTRATION),*];
+    };
+}
 
+/// Register the solutions of the year modules.
+/// Every listed module must invoke `register_days!` with the days of its year.
+macro_rules! register_years {
+    ($($year:ident),* $(,)?) => {
+        static SOLUTIONS: &[&[registry::Registration]] = &[$($year::SOLUTIONS),*];
+    };
+}
+
+register_years!(year2023);
+
+pub mod answers;
+pub mod assumptions;
+pub mod bench;
+pub mod cancellation;
 pub mod common;
+pub mod cycle;
+#[allow(dead_code)]
 mod dayxx;
+pub mod examples;
+pub mod geometry;
+pub mod grid;
+pub mod interval;
+pub mod math;
+pub mod memory;
 pub mod models;
+pub mod parameters;
+pub mod parser;
+pub mod registry;
+pub mod runner;
+pub mod scaffold;
+pub mod watch;
+pub mod year2023;
@@ -1,36 +1,28 @@
-use std::fs;
-use std::path::PathBuf;
-use std::time::Instant;
-
-use advent_2023::day01::Day01;
-use advent_2023::day02::Day02;
-use advent_2023::day03::Day03;
-use advent_2023::day04::Day04;
-use advent_2023::day05::Day05;
-use advent_2023::day06::Day06;
-use advent_2023::day07::Day07;
-use advent_2023::day08::Day08;
-use advent_2023::day09::Day09;
-use advent_2023::day10::Day10;
-use advent_2023::day11::Day11;
-use advent_2023::day12::Day12;
-use advent_2023::day13::Day13;
-use advent_2023::day14::Day14;
-use advent_2023::day15::Day15;
-use advent_2023::day16::Day16;
-use advent_2023::day17::Day17;
-use advent_2023::day18::Day18;
-use advent_2023::day19::Day19;
-use advent_2023::day20::Day20;
-use advent_2023::day21::Day21;
-use advent_2023::day22::Day22;
-use advent_2023::day23::Day23;
-use advent_2023::day24::Day24;
-use advent_2023::day25::Day25;
-use advent_2023::models::AdventSolution;
-use clap::{Args, Parser, Subcommand};
-use comfy_table::presets::UTF8_FULL;
-use comfy_table::{Cell, ContentArrangement, Table};
+use std::io::{self, StdoutLock, Write};
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::process::exit;
+use std::time::{Duration, Instant};
+
+use advent_2023::answers::{verify, Answers};
+use advent_2023::assumptions::assumptions_table;
+use advent_2023::bench::{bench_days, bench_table, Baseline};
+use advent_2023::examples::{manifest_path, Manifest};
+use advent_2023::parameters::ParameterAssignment;
+use advent_2023::registry::{self, solutions_table, Days, Registration};
+use advent_2023::runner::{
+    check_input, check_parameters, count_mismatches, generate_input, input_path, inspect, is_catching_panic,
+    read_input, reports_table, reports_to_json, run_days, run_examples, run_file, split_results, write_reports_csv,
+    Part, RunError, RunReport, Stage,
+};
+use advent_2023::scaffold::new_day;
+use advent_2023::watch::watch_day;
+use clap::error::ErrorKind;
+use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum};
+
+#[cfg(feature = "memory")]
+#[global_allocator]
+static ALLOCATOR: advent_2023::memory::CountingAllocator = advent_2023::memory::CountingAllocator;
 
 #[derive(Parser, Debug, Eq, PartialEq, Clone)]
 struct Cli {
@@ -38,6 +30,11 @@ struct Cli {
     #[arg(short, long, default_value_t = false)]
     pub verbose: bool,
 
+    /// Year of the solutions, the latest year having solutions if not set. The list command shows every year if
+    /// not set
+    #[arg(short, long, global = true)]
+    pub year: Option<usize>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -49,156 +46,604 @@ enum Commands {
 
     /// Run a specific day
     Day(DayArgs),
+
+    /// Check the solutions against the expected answers
+    Verify(VerifyArgs),
+
+    /// Benchmark every phase of the solutions
+    Bench(BenchArgs),
+
+    /// List the available solutions
+    List,
+
+    /// Create the module of a new day from the dayxx template
+    NewDay(NewDayArgs),
+
+    /// Check that an input has the properties a solution relies on, without solving it
+    Check(CheckArgs),
+
+    /// Generate a random input for a day, printed on the standard output
+    Generate(GenerateArgs),
+
+    /// Render the parsed or prepared data of a day for an input
+    Inspect(InspectArgs),
+}
+
+#[derive(ValueEnum, Debug, Eq, PartialEq, Clone, Copy)]
+enum OutputFormat {
+    Table,
+    Json,
+    Csv,
+}
+
+/// Parse a positive duration given in seconds
+fn parse_timeout(value: &str) -> Result<Duration, String> {
+    match value.parse::<f64>() {
+        Ok(seconds) if seconds > 0.0 && seconds.is_finite() => Ok(Duration::from_secs_f64(seconds)),
+        _ => Err(format!("`{value}` is not a positive number of seconds")),
+    }
 }
 
 #[derive(Args, Debug, Eq, PartialEq, Clone)]
 struct AllArgs {
-    /// If set, use input present in the inputs directory
+    /// If set, use input present in the inputs directory, otherwise run every example of the manifest
     #[arg(short, long, default_value_t = false)]
     pub use_real_input: bool,
+
+    /// Days to run, as a list of days and ranges such as `3,5,10-14`. Every day is run if not set
+    #[arg(short, long)]
+    pub days: Option<Days>,
+
+    /// Parts to run: 1, 2 or both
+    #[arg(short, long, default_value_t = Part::Both)]
+    pub part: Part,
+
+    /// Set a puzzle parameter of the days declaring it, can be repeated
+    #[arg(long = "param", value_name = "NAME=VALUE")]
+    pub params: Vec<ParameterAssignment>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+
+    /// Time budget of each day in seconds, the parts not solved in time are reported as timed out.
+    /// Only the long-running solutions checking a cancellation token stop when it elapses: the other ones run to the
+    /// end before being reported as timed out
+    #[arg(short, long, value_name = "SECONDS", value_parser = parse_timeout)]
+    pub timeout: Option<Duration>,
+
+    /// Number of days solved concurrently
+    #[arg(short, long, default_value_t = 1)]
+    pub jobs: usize,
 }
 
 #[derive(Args, Debug, Eq, PartialEq, Clone)]
 struct DayArgs {
+    /// Days to run, as a list of days and ranges such as `3,5,10-14`
+    pub days: Days,
+
+    /// File to parse, `-` reads the standard input. If not set, the input of each day is used
+    pub path: Option<PathBuf>,
+
+    /// If set, use input present in the inputs directory when no file is given
+    #[arg(short, long, default_value_t = false)]
+    pub use_real_input: bool,
+
+    /// Parts to run: 1, 2 or both
+    #[arg(short, long, default_value_t = Part::Both)]
+    pub part: Part,
+
+    /// Set a puzzle parameter of the days declaring it, can be repeated
+    #[arg(long = "param", value_name = "NAME=VALUE")]
+    pub params: Vec<ParameterAssignment>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value_t = OutputFormat::Table)]
+    pub format: OutputFormat,
+
+    /// Time budget of each day in seconds, the parts not solved in time are reported as timed out.
+    /// Only the long-running solutions checking a cancellation token stop when it elapses: the other ones run to the
+    /// end before being reported as timed out
+    #[arg(short, long, value_name = "SECONDS", value_parser = parse_timeout)]
+    pub timeout: Option<Duration>,
+
+    /// Run the day again every time its input file changes, until interrupted
+    #[arg(short, long, default_value_t = false)]
+    pub watch: bool,
+
+    /// In watch mode, also run the examples of the day listed in the manifest when they change
+    #[arg(long, default_value_t = false, requires = "watch")]
+    pub watch_examples: bool,
+
+    /// In watch mode, delay between two checks of the files in milliseconds
+    #[arg(long, value_name = "MILLISECONDS", default_value_t = 500, requires = "watch")]
+    pub interval: u64,
+}
+
+#[derive(Args, Debug, Eq, PartialEq, Clone)]
+struct VerifyArgs {
+    /// Days to verify, as a list of days and ranges such as `3,5,10-14`. Every day is verified if not set
+    pub days: Option<Days>,
+
+    /// If set, use input present in the inputs directory
+    #[arg(short, long, default_value_t = false)]
+    pub use_real_input: bool,
+
+    /// File storing the expected answers
+    #[arg(short, long, default_value = "answers.txt")]
+    pub answers: PathBuf,
+
+    /// If set, write the current answers in the answers file instead of checking them
+    #[arg(short, long, default_value_t = false)]
+    pub record: bool,
+
+    /// Set a puzzle parameter of the days declaring it, can be repeated. The answers file does not record the
+    /// parameters, use another answers file to verify non-default parameters
+    #[arg(long = "param", value_name = "NAME=VALUE")]
+    pub params: Vec<ParameterAssignment>,
+
+    /// Time budget of each day in seconds, the parts not solved in time are reported as timed out.
+    /// Only the long-running solutions checking a cancellation token stop when it elapses: the other ones run to the
+    /// end before being reported as timed out
+    #[arg(short, long, value_name = "SECONDS", value_parser = parse_timeout)]
+    pub timeout: Option<Duration>,
+}
+
+#[derive(Args, Debug, Eq, PartialEq, Clone)]
+struct BenchArgs {
+    /// Days to benchmark, as a list of days and ranges such as `3,5,10-14`. Every day is benchmarked if not set
+    pub days: Option<Days>,
+
+    /// If set, use input present in the inputs directory
+    #[arg(short, long, default_value_t = false)]
+    pub use_real_input: bool,
+
+    /// Set a puzzle parameter of the days declaring it, can be repeated
+    #[arg(long = "param", value_name = "NAME=VALUE")]
+    pub params: Vec<ParameterAssignment>,
+
+    /// Number of measured runs
+    #[arg(short = 'n', long, default_value_t = 10)]
+    pub iterations: usize,
+
+    /// Number of runs performed before measuring
+    #[arg(short, long, default_value_t = 2)]
+    pub warmup: usize,
+
+    /// Save the results in this file to use them as a baseline later
+    #[arg(short, long)]
+    pub save_baseline: Option<PathBuf>,
+
+    /// Compare the results with the baseline stored in this file
+    #[arg(short, long)]
+    pub baseline: Option<PathBuf>,
+
+    /// Median slowdown, in percent, above which a phase is flagged as a regression
+    #[arg(short, long, default_value_t = 10)]
+    pub threshold: u32,
+}
+
+#[derive(Args, Debug, Eq, PartialEq, Clone)]
+struct NewDayArgs {
+    /// Day to create
+    pub day: usize,
+}
+
+#[derive(Args, Debug, Eq, PartialEq, Clone)]
+struct CheckArgs {
     /// Day
     pub day: usize,
 
-    /// File to parse
+    /// File to check, `-` reads the standard input
     pub path: PathBuf,
 }
 
-struct RunDaySolution {
-    day: usize,
-    part_01_sol: i128,
-    part_02_sol: i128,
-    parse_time: u128,
-    prep_time: u128,
-    part_01_time: u128,
-    part_02_time: u128,
-}
-
-fn run_day(day: usize, solution: &mut Box<dyn AdventSolution>, input: String) -> RunDaySolution {
-    // Parse the data
-    let now = Instant::now();
-    solution.parse(input);
-    let parse_time = now.elapsed().as_micros();
-
-    // Prepare the parsed_data
-    let now = Instant::now();
-    solution.prepare();
-    let prep_time = now.elapsed().as_micros();
-
-    // Solve part one
-    let now = Instant::now();
-    let part_01_sol = solution.solve_part_one();
-    let part_01_time = now.elapsed().as_micros();
-
-    // Solve part two
-    let now = Instant::now();
-    let part_02_sol = solution.solve_part_two();
-    let part_02_time = now.elapsed().as_micros();
-
-    RunDaySolution {
-        day,
-        part_01_sol,
-        part_02_sol,
-        parse_time,
-        prep_time,
-        part_01_time,
-        part_02_time,
+#[derive(Args, Debug, Eq, PartialEq, Clone)]
+struct GenerateArgs {
+    /// Day
+    pub day: usize,
+
+    /// Size of the generated input, its meaning depends on the day
+    #[arg(short, long, default_value_t = 10)]
+    pub size: usize,
+
+    /// Seed of the random generator, a random seed is used and printed on the standard error if missing
+    #[arg(long)]
+    pub seed: Option<u64>,
+}
+
+#[derive(Args, Debug, Eq, PartialEq, Clone)]
+struct InspectArgs {
+    /// Day
+    pub day: usize,
+
+    /// File to inspect, `-` reads the standard input
+    pub path: PathBuf,
+
+    /// Render the data once parsed or once prepared: parsed or prepared
+    #[arg(short, long, default_value_t = Stage::Parsed)]
+    pub stage: Stage,
+
+    /// Set a puzzle parameter of the day, can be repeated
+    #[arg(long = "param", value_name = "NAME=VALUE")]
+    pub params: Vec<ParameterAssignment>,
+}
+
+/// Exit with a usage error
+fn usage_error(kind: ErrorKind, message: impl std::fmt::Display) -> ! {
+    Cli::command().error(kind, message).exit()
+}
+
+/// Get the registered solutions of the days, every day of the year if `days` is not set. Exits with a usage error
+/// if one of them has no solution
+fn select_days(year: usize, days: Option<&Days>) -> Vec<&'static Registration> {
+    match days {
+        Some(days) => registry::select_days(year, days),
+        None => registry::year_solutions(year),
     }
+    .unwrap_or_else(|message| usage_error(ErrorKind::ValueValidation, message))
 }
 
-fn main() {
-    let mut solvers: Vec<Box<dyn AdventSolution>> = vec![
-        Box::<Day01>::default(),
-        Box::<Day02>::default(),
-        Box::<Day03>::default(),
-        Box::<Day04>::default(),
-        Box::<Day05>::default(),
-        Box::<Day06>::default(),
-        Box::<Day07>::default(),
-        Box::<Day08>::default(),
-        Box::<Day09>::default(),
-        Box::<Day10>::default(),
-        Box::<Day11>::default(),
-        Box::<Day12>::default(),
-        Box::<Day13>::default(),
-        Box::<Day14>::default(),
-        Box::<Day15>::default(),
-        Box::<Day16>::default(),
-        Box::<Day17>::default(),
-        Box::<Day18>::default(),
-        Box::<Day19>::default(),
-        Box::<Day20>::default(),
-        Box::<Day21>::default(),
-        Box::<Day22>::default(),
-        Box::<Day23>::default(),
-        Box::<Day24>::default(),
-        Box::<Day25>::default(),
-    ];
+/// Get the registered solution of a day, exiting with a usage error if it has no solution
+fn select_day(year: usize, day: usize) -> &'static Registration {
+    select_days(year, Some(&Days(vec![day])))[0]
+}
+
+/// Load the example manifest of a year, exiting if it cannot be read
+fn load_manifest(year: usize) -> Manifest {
+    let path = manifest_path(year);
+    Manifest::load(Path::new(&path), year).unwrap_or_else(|error| {
+        eprintln!("Unable to read {path}: {error}");
+        exit(1);
+    })
+}
+
+/// Read an input file, exiting if it cannot be read
+fn load_input(path: &Path) -> String {
+    read_input(&path.display().to_string()).unwrap_or_else(|error| {
+        eprintln!("{error}");
+        exit(1);
+    })
+}
+
+/// Print the errors of the runs, returning the successful runs and whether an error occurred
+fn print_errors(results: Vec<Result<RunReport, RunError>>) -> (Vec<RunReport>, bool) {
+    let (reports, errors) = split_results(results);
+    for error in &errors {
+        eprintln!("{error}");
+    }
+
+    (reports, !errors.is_empty())
+}
 
+/// Do not display the panics caught by the runner, the commands report them in their results.
+/// The other panics are still displayed by the previous hook.
+fn silence_panics() {
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if !is_catching_panic() {
+            previous_hook(info);
+        }
+    }));
+}
+
+/// Print the reports in the chosen format, the table format is printed by `print_table`. Exits if the standard output
+/// cannot be written, for instance when it is a closed pipe
+fn print_reports(
+    format: OutputFormat,
+    reports: &[RunReport],
+    print_table: impl FnOnce(&mut StdoutLock) -> io::Result<()>,
+) {
+    let mut stdout = io::stdout().lock();
+    let result = match format {
+        OutputFormat::Table => print_table(&mut stdout).map_err(csv::Error::from),
+        OutputFormat::Json => writeln!(stdout, "{}", reports_to_json(reports)).map_err(csv::Error::from),
+        OutputFormat::Csv => write_reports_csv(reports, &mut stdout),
+    };
+    if let Err(error) = result {
+        eprintln!("Unable to print the reports: {error}");
+        exit(1);
+    }
+}
+
+fn main() {
     let arguments = Cli::parse();
+    let year = arguments
+        .year
+        .unwrap_or_else(|| *registry::years().last().expect("no solution is registered"));
 
     match arguments.command {
         Commands::All(all_args) => {
-            let mut table = Table::new();
-            let mut total_time: u128 = 0;
-            table
-                .load_preset(UTF8_FULL)
-                .set_content_arrangement(ContentArrangement::Dynamic)
-                .set_header(vec![
-                    "Day",
-                    "Part 1 solution",
-                    "Part 2 solution",
-                    "Parse time",
-                    "Prep time",
-                    "Part 1 time",
-                    "Part 2 time",
-                    "Tot time",
-                ]);
-            for (i, solver) in solvers.iter_mut().enumerate() {
-                let input = if all_args.use_real_input {
-                    fs::read_to_string(format!("inputs/day{:0>2}", i + 1)).unwrap()
+            let registrations = select_days(year, all_args.days.as_ref());
+            if let Err(message) = check_parameters(&registrations, &all_args.params) {
+                usage_error(ErrorKind::ValueValidation, message);
+            }
+
+            silence_panics();
+
+            let now = Instant::now();
+            let results = if all_args.use_real_input {
+                run_days(
+                    &registrations,
+                    all_args.part,
+                    &all_args.params,
+                    all_args.timeout,
+                    all_args.use_real_input,
+                    all_args.jobs,
+                )
+            } else {
+                let manifest = load_manifest(year);
+                run_examples(
+                    &registrations,
+                    &manifest,
+                    all_args.part,
+                    &all_args.params,
+                    all_args.timeout,
+                    all_args.jobs,
+                )
+            };
+            let wall_time = now.elapsed().as_micros();
+            let (reports, failed) = print_errors(results);
+            let mismatches = count_mismatches(&reports);
+
+            print_reports(all_args.format, &reports, |stdout| {
+                let total_time: u128 = reports.iter().map(|report| report.total_time).sum();
+                writeln!(
+                    stdout,
+                    "Advent of code {year} solutions (every time is displayed in microseconds):"
+                )?;
+                writeln!(stdout, "{}", reports_table(&reports))?;
+                writeln!(
+                    stdout,
+                    "Total execution time (excluding file loading time): {total_time} microseconds"
+                )?;
+                if all_args.jobs > 1 {
+                    writeln!(
+                        stdout,
+                        "Wall clock time with {} jobs: {wall_time} microseconds",
+                        all_args.jobs
+                    )?;
+                }
+                if mismatches > 0 {
+                    writeln!(stdout, "{mismatches} answers differ from the expected ones")?;
+                }
+
+                Ok(())
+            });
+            if failed || mismatches > 0 {
+                exit(1);
+            }
+        }
+        Commands::Day(day_args) => {
+            let registrations = select_days(year, Some(&day_args.days));
+            if let Err(message) = check_parameters(&registrations, &day_args.params) {
+                usage_error(ErrorKind::ValueValidation, message);
+            }
+            if day_args.path.is_some() && registrations.len() > 1 {
+                usage_error(
+                    ErrorKind::ArgumentConflict,
+                    "a single day must be given when reading an input file",
+                );
+            }
+            silence_panics();
+            let path_of = |registration: &Registration| match &day_args.path {
+                Some(path) => path.display().to_string(),
+                None => input_path(year, registration.metadata.day, day_args.use_real_input),
+            };
+
+            if day_args.watch {
+                if registrations.len() > 1 {
+                    usage_error(ErrorKind::ArgumentConflict, "a single day must be given in watch mode");
+                }
+                let path = path_of(registrations[0]);
+                if path == "-" {
+                    usage_error(ErrorKind::ArgumentConflict, "the standard input cannot be watched");
+                }
+                let examples = if day_args.watch_examples {
+                    load_manifest(year)
+                        .examples(registrations[0].metadata.day)
+                        .cloned()
+                        .collect()
                 } else {
-                    fs::read_to_string(format!("input_examples/day{:0>2}", i + 1)).unwrap()
+                    vec![]
                 };
+                watch_day(
+                    registrations[0],
+                    path,
+                    examples,
+                    day_args.part,
+                    &day_args.params,
+                    day_args.timeout,
+                    Duration::from_millis(day_args.interval),
+                );
+            }
 
-                let solution = run_day(i + 1, solver, input);
-                let day_time = solution.parse_time + solution.prep_time + solution.part_01_time + solution.part_02_time;
-                total_time += day_time;
-                table.add_row(vec![
-                    Cell::new(solution.day),
-                    Cell::new(solution.part_01_sol),
-                    Cell::new(solution.part_02_sol),
-                    Cell::new(solution.parse_time),
-                    Cell::new(solution.prep_time),
-                    Cell::new(solution.part_01_time),
-                    Cell::new(solution.part_02_time),
-                    Cell::new(day_time),
-                ]);
-            }
-            println!("Advent of code 2023 solutions (every time is displayed in microseconds):");
-            println!("{table}");
-            println!("Total execution time (excluding file loading time): {total_time} microseconds");
+            let results = registrations
+                .into_iter()
+                .map(|registration| {
+                    run_file(
+                        registration,
+                        day_args.part,
+                        &day_args.params,
+                        day_args.timeout,
+                        &path_of(registration),
+                    )
+                })
+                .collect();
+            let (reports, failed) = print_errors(results);
+
+            // Display the results
+            print_reports(day_args.format, &reports, |stdout| {
+                reports.iter().try_for_each(|report| writeln!(stdout, "{report}"))
+            });
+
+            if failed || reports.iter().any(RunReport::has_failed_part) {
+                exit(1);
+            }
         }
-        Commands::Day(day_args) => {
-            let input = fs::read_to_string(day_args.path).unwrap();
-            let solution = run_day(day_args.day, &mut solvers[day_args.day - 1], input);
+        Commands::Verify(verify_args) => {
+            let mut answers = match Answers::load(&verify_args.answers) {
+                Ok(answers) => answers,
+                Err(error) => {
+                    eprintln!("Unable to load {}: {error}", verify_args.answers.display());
+                    exit(1);
+                }
+            };
+            let registrations = select_days(year, verify_args.days.as_ref());
+            if let Err(message) = check_parameters(&registrations, &verify_args.params) {
+                usage_error(ErrorKind::ValueValidation, message);
+            }
+            silence_panics();
+
+            let verification = verify(
+                &registrations,
+                &mut answers,
+                &verify_args.params,
+                verify_args.timeout,
+                verify_args.use_real_input,
+                verify_args.record,
+            );
+            for error in &verification.errors {
+                eprintln!("{error}");
+            }
+            println!("{}", verification.table);
 
-            // Display the result
+            if verify_args.record {
+                if let Err(error) = answers.save(&verify_args.answers) {
+                    eprintln!("Unable to save {}: {error}", verify_args.answers.display());
+                    exit(1);
+                }
+                println!("Answers recorded in {}", verify_args.answers.display());
+            }
+
+            if verification.failed {
+                exit(1);
+            }
+        }
+        Commands::Bench(bench_args) => {
+            let baseline = match &bench_args.baseline {
+                None => Baseline::default(),
+                Some(path) => match Baseline::load(path) {
+                    Ok(baseline) => baseline,
+                    Err(error) => {
+                        eprintln!("Unable to read the baseline {}: {error}", path.display());
+                        exit(1);
+                    }
+                },
+            };
+            let registrations = select_days(year, bench_args.days.as_ref());
+            if let Err(message) = check_parameters(&registrations, &bench_args.params) {
+                usage_error(ErrorKind::ValueValidation, message);
+            }
+
+            silence_panics();
+
+            let benches: Vec<_> = bench_days(
+                &registrations,
+                &bench_args.params,
+                bench_args.use_real_input,
+                bench_args.warmup,
+                bench_args.iterations.max(1),
+            )
+            .into_iter()
+            .filter_map(|result| result.inspect_err(|error| eprintln!("{error}")).ok())
+            .collect();
+            let (table, regression) = bench_table(&benches, &baseline, bench_args.threshold);
             println!(
-                "Day {:0>2}, results: {:>14}, {:>14}, \
-                parse_time: {:>10} us, prep_time: {:>10} us, \
-                part_01_time: {:>10} us, part_02_time: {:>10} us",
-                solution.day,
-                solution.part_01_sol,
-                solution.part_02_sol,
-                solution.parse_time,
-                solution.prep_time,
-                solution.part_01_time,
-                solution.part_02_time
+                "Benchmark over {} runs after {} warmup runs (every time is displayed in microseconds):",
+                bench_args.iterations.max(1),
+                bench_args.warmup
             );
+            println!("{table}");
+
+            if let Some(path) = &bench_args.save_baseline {
+                if let Err(error) = Baseline::save(&benches, path) {
+                    eprintln!("Unable to save the baseline {}: {error}", path.display());
+                    exit(1);
+                }
+                println!("Baseline saved in {}", path.display());
+            }
+
+            if regression {
+                println!(
+                    "Some phases are more than {}% slower than the baseline",
+                    bench_args.threshold
+                );
+                exit(1);
+            }
+        }
+        Commands::List => match solutions_table(arguments.year) {
+            Ok(table) => println!("{table}"),
+            Err(message) => usage_error(ErrorKind::ValueValidation, message),
+        },
+        Commands::NewDay(new_day_args) => {
+            let day = new_day_args.day;
+            if let Err(error) = new_day(Path::new("."), year, day) {
+                eprintln!("Unable to create day {day:0>2} of {year}: {error}");
+                exit(1);
+            }
+            println!(
+                "Day {day:0>2} of {year} created in src/year{year}/day{day:0>2}, fill input_examples/{year}/day{day:0>2} \
+                with the example input and its answers in {}",
+                manifest_path(year)
+            );
+        }
+        Commands::Check(check_args) => {
+            let registration = select_day(year, check_args.day);
+            let assumptions = match check_input(registration, load_input(&check_args.path)) {
+                Ok(assumptions) => assumptions,
+                Err(error) => {
+                    eprintln!("{error}");
+                    exit(1);
+                }
+            };
+            if assumptions.is_empty() {
+                println!(
+                    "Day {:0>2} does not declare any assumption on its input",
+                    check_args.day
+                );
+                return;
+            }
+
+            println!("{}", assumptions_table(&assumptions));
+            if !assumptions.iter().all(|assumption| assumption.holds()) {
+                exit(1);
+            }
+        }
+        Commands::Generate(generate_args) => {
+            let registration = select_day(year, generate_args.day);
+            let seed = generate_args.seed.unwrap_or_else(|| {
+                let seed = rand::random();
+                eprintln!("Seed: {seed}");
+                seed
+            });
+
+            match generate_input(registration, generate_args.size, seed) {
+                Some(input) => print!("{input}"),
+                None => {
+                    eprintln!("Day {:0>2} does not have an input generator", generate_args.day);
+                    exit(1);
+                }
+            }
+        }
+        Commands::Inspect(inspect_args) => {
+            let registration = select_day(year, inspect_args.day);
+            if let Err(message) = check_parameters(&[registration], &inspect_args.params) {
+                usage_error(ErrorKind::ValueValidation, message);
+            }
+
+            let input = load_input(&inspect_args.path);
+            match inspect(registration, &inspect_args.params, inspect_args.stage, input) {
+                Ok(Some(rendered)) => println!("{rendered}"),
+                Ok(None) => {
+                    eprintln!("Day {:0>2} cannot render its data", inspect_args.day);
+                    exit(1);
+                }
+                Err(error) => {
+                    eprintln!("{error}");
+                    exit(1);
+                }
+            }
         }
     }
 }
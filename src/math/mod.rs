@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod tests;
+
+/// Implementation of the extended euclidean algorithm, taken from
+/// https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm.
+/// Returns the tuple `(left_coef, right_coef, gcd)` where `left_coef * a + right_coef * b = gcd`,
+/// the gcd being positive or zero.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (1, 0);
+    let (mut old_t, mut t) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+        (old_t, t) = (t, old_t - quotient * t);
+    }
+
+    if old_r < 0 {
+        (-old_s, -old_t, -old_r)
+    } else {
+        (old_s, old_t, old_r)
+    }
+}
+
+/// Greatest common divisor, positive or zero
+pub fn gcd(a: i128, b: i128) -> i128 {
+    extended_gcd(a, b).2
+}
+
+/// Least common multiple, positive or zero
+pub fn lcm(a: i128, b: i128) -> i128 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+
+    (a / gcd(a, b) * b).abs()
+}
+
+/// Inverse of `value` modulo `modulus`, between 0 and `modulus - 1`.
+/// `None` if `value` and `modulus` are not coprime.
+pub fn mod_inverse(value: i128, modulus: i128) -> Option<i128> {
+    assert!(modulus > 0, "the modulus must be positive");
+
+    let (coef, _, gcd) = extended_gcd(value.rem_euclid(modulus), modulus);
+    (gcd == 1).then(|| coef.rem_euclid(modulus))
+}
+
+/// Chinese remainder theorem for periods that may not be coprime: find the values `t` such that
+/// `t = offset (mod period)` for both `(offset, period)` pairs.
+/// Returns them as an `(offset, period)` pair, with an offset between 0 and the period minus one,
+/// or `None` if no value satisfies both equations.
+pub fn crt((offset_1, period_1): (i128, i128), (offset_2, period_2): (i128, i128)) -> Option<(i128, i128)> {
+    assert!(period_1 > 0 && period_2 > 0, "the periods must be positive");
+
+    // Solve offset_1 + period_1 * k = offset_2 (mod period_2), that needs the gcd to divide the difference
+    let (coef, _, gcd) = extended_gcd(period_1, period_2);
+    let difference = offset_2 - offset_1;
+    if difference % gcd != 0 {
+        return None;
+    }
+
+    // `coef` is the inverse of period_1 / gcd modulo period_2 / gcd
+    let reduced_period = period_2 / gcd;
+    let k = (difference / gcd).rem_euclid(reduced_period) * coef.rem_euclid(reduced_period) % reduced_period;
+    let period = period_1 * reduced_period;
+
+    Some(((offset_1 + period_1 * k).rem_euclid(period), period))
+}
+
+/// Find when two periodic events happen together, the events happening at `offset + period * n`
+/// for every positive or zero integer `n`.
+/// Returns the first moment both events happen and the period of the common moments,
+/// or `None` if they never happen together.
+pub fn merge_periodicities(first: (i128, i128), second: (i128, i128)) -> Option<(i128, i128)> {
+    let (offset, period) = crt(first, second)?;
+
+    // Neither event happens before its own offset
+    let start = first.0.max(second.0);
+    Some((start + (offset - start).rem_euclid(period), period))
+}
+
+/// Exact integer square root, unlike a conversion to a float that loses precision on large values
+pub trait IntegerSqrt: Sized {
+    /// Largest integer whose square is lower or equal to the value, panics if the value is negative
+    fn floor_sqrt(self) -> Self;
+
+    /// Square root of the value if it is a perfect square, panics if the value is negative
+    fn exact_sqrt(self) -> Option<Self>;
+}
+
+macro_rules! impl_integer_sqrt {
+    ($($integer:ty),*) => {
+        $(
+            impl IntegerSqrt for $integer {
+                fn floor_sqrt(self) -> Self {
+                    self.isqrt()
+                }
+
+                fn exact_sqrt(self) -> Option<Self> {
+                    let root = self.isqrt();
+                    (root * root == self).then_some(root)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_sqrt!(i64, i128);
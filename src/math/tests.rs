@@ -0,0 +1,115 @@
+use crate::math::{crt, extended_gcd, gcd, lcm, merge_periodicities, mod_inverse, IntegerSqrt};
+
+fn gcd_bruteforce(a: i128, b: i128) -> i128 {
+    (1..=a.abs().max(b.abs()))
+        .rev()
+        .find(|divisor| a % divisor == 0 && b % divisor == 0)
+        .unwrap_or(0)
+}
+
+#[test]
+fn test_extended_gcd() {
+    for a in -30..=30i128 {
+        for b in -30..=30 {
+            let (left_coef, right_coef, gcd) = extended_gcd(a, b);
+            assert_eq!(gcd, gcd_bruteforce(a, b), "gcd of {a} and {b}");
+            assert_eq!(
+                left_coef * a + right_coef * b,
+                gcd,
+                "bezout coefficients of {a} and {b}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_lcm() {
+    for a in -30..=30i128 {
+        for b in -30..=30 {
+            let expected = if a == 0 || b == 0 {
+                0
+            } else {
+                (1..).map(|k| k * a.abs()).find(|multiple| multiple % b == 0).unwrap()
+            };
+            assert_eq!(lcm(a, b), expected, "lcm of {a} and {b}");
+            assert_eq!(gcd(a, b) * lcm(a, b), (a * b).abs());
+        }
+    }
+}
+
+#[test]
+fn test_mod_inverse() {
+    for modulus in 1..=30i128 {
+        for value in -30..=30 {
+            let expected = (0..modulus).find(|inverse| (value * inverse - 1).rem_euclid(modulus) == 0);
+            assert_eq!(
+                mod_inverse(value, modulus),
+                expected,
+                "inverse of {value} modulo {modulus}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_crt() {
+    for period_1 in 1..=12 {
+        for period_2 in 1..=12 {
+            for offset_1 in -period_1..2 * period_1 {
+                for offset_2 in -period_2..2 * period_2 {
+                    let expected = (0..period_1 * period_2)
+                        .find(|t| (t - offset_1) % period_1 == 0 && (t - offset_2) % period_2 == 0)
+                        .map(|t| (t, lcm(period_1, period_2)));
+                    assert_eq!(
+                        crt((offset_1, period_1), (offset_2, period_2)),
+                        expected,
+                        "t = {offset_1} (mod {period_1}) and t = {offset_2} (mod {period_2})"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_merge_periodicities() {
+    for period_1 in 1..=12 {
+        for period_2 in 1..=12 {
+            for offset_1 in 0..20 {
+                for offset_2 in 0..20 {
+                    let expected = (0..period_1 * period_2 + offset_1)
+                        .map(|n| offset_2 + period_2 * n)
+                        .find(|t| *t >= offset_1 && (t - offset_1) % period_1 == 0)
+                        .map(|t| (t, lcm(period_1, period_2)));
+                    assert_eq!(
+                        merge_periodicities((offset_1, period_1), (offset_2, period_2)),
+                        expected,
+                        "events at {offset_1} + {period_1}n and {offset_2} + {period_2}n"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_integer_sqrt() {
+    for value in 0..10_000i64 {
+        let expected = (0..=value).take_while(|root| root * root <= value).last().unwrap();
+        assert_eq!(value.floor_sqrt(), expected, "square root of {value}");
+        assert_eq!(value.exact_sqrt(), (expected * expected == value).then_some(expected));
+        assert_eq!((value as i128).floor_sqrt(), expected as i128);
+    }
+
+    // Values where a conversion to f64 rounds to the wrong root
+    let root = 3_037_000_499i64;
+    assert_eq!((root * root).exact_sqrt(), Some(root));
+    assert_eq!((root * root - 1).floor_sqrt(), root - 1);
+    assert_eq!((root * root - 1).exact_sqrt(), None);
+    assert_eq!(i64::MAX.floor_sqrt(), root);
+
+    let root = 1_000_000_000_000_000_007i128;
+    assert_eq!((root * root).exact_sqrt(), Some(root));
+    assert_eq!((root * root - 1).floor_sqrt(), root - 1);
+    assert_eq!((root * root + 1).exact_sqrt(), None);
+}
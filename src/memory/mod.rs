@@ -0,0 +1,122 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU64, AtomicUsize, Ordering};
+
+use serde::Serialize;
+
+// The tests install `CountingAllocator` as the global allocator of the test binary
+#[cfg(all(test, feature = "memory"))]
+mod tests;
+
+/// Set by `CountingAllocator` on its first allocation, the counters stay at zero otherwise
+static TRACKING: AtomicBool = AtomicBool::new(false);
+
+// The allocations of every thread are counted, so that the rayon workers of a solution are measured as well
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE_BYTES: AtomicI64 = AtomicI64::new(0);
+static PEAK_BYTES: AtomicI64 = AtomicI64::new(0);
+
+/// Number of measurements in progress and number of measurements started, to detect concurrent measurements
+static ACTIVE_MEASUREMENTS: AtomicUsize = AtomicUsize::new(0);
+static STARTED_MEASUREMENTS: AtomicUsize = AtomicUsize::new(0);
+
+/// Global allocator counting the allocations of every thread, to be installed by the binary with
+/// `#[global_allocator]`. The memory is then measured by `measure`.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn record_allocation(size: usize) {
+        if !TRACKING.load(Ordering::Relaxed) {
+            TRACKING.store(true, Ordering::Relaxed);
+        }
+
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(size as u64, Ordering::Relaxed);
+        let live = LIVE_BYTES.fetch_add(size as i64, Ordering::Relaxed) + size as i64;
+        PEAK_BYTES.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn record_deallocation(size: usize) {
+        LIVE_BYTES.fetch_sub(size as i64, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        Self::record_allocation(layout.size());
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        Self::record_deallocation(layout.size());
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        Self::record_allocation(layout.size());
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        Self::record_deallocation(layout.size());
+        Self::record_allocation(new_size);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Memory allocated while running a function, a reallocation counts as a new allocation
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default, Serialize)]
+pub struct MemoryUsage {
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+    /// Highest amount of memory allocated by the function and not freed yet
+    pub peak_bytes: u64,
+    /// Whether other functions were measured at the same time, their allocations are then counted as well
+    pub concurrent: bool,
+}
+
+impl MemoryUsage {
+    /// Usage of two functions run one after the other
+    pub fn then(self, other: MemoryUsage) -> MemoryUsage {
+        MemoryUsage {
+            allocations: self.allocations + other.allocations,
+            allocated_bytes: self.allocated_bytes + other.allocated_bytes,
+            peak_bytes: self.peak_bytes.max(other.peak_bytes),
+            concurrent: self.concurrent || other.concurrent,
+        }
+    }
+}
+
+/// Check if `CountingAllocator` is the global allocator
+pub fn is_tracking() -> bool {
+    TRACKING.load(Ordering::Relaxed)
+}
+
+/// Run the function and measure the memory it allocates, `None` if `CountingAllocator` is not the global allocator.
+/// The allocations of every thread are counted, including the threads the function spawns or runs work on. The
+/// usage of functions measured concurrently, such as solutions run on several jobs, is flagged as `concurrent`
+/// since it includes the allocations of the other functions.
+pub fn measure<T>(function: impl FnOnce() -> T) -> (T, Option<MemoryUsage>) {
+    if !is_tracking() {
+        return (function(), None);
+    }
+
+    let started = STARTED_MEASUREMENTS.fetch_add(1, Ordering::SeqCst) + 1;
+    let concurrent_at_start = ACTIVE_MEASUREMENTS.fetch_add(1, Ordering::SeqCst) > 0;
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated_bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let live_bytes = LIVE_BYTES.load(Ordering::Relaxed);
+    PEAK_BYTES.store(live_bytes, Ordering::Relaxed);
+
+    let result = function();
+
+    let usage = MemoryUsage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - allocated_bytes,
+        peak_bytes: (PEAK_BYTES.load(Ordering::Relaxed) - live_bytes).max(0) as u64,
+        concurrent: concurrent_at_start || STARTED_MEASUREMENTS.load(Ordering::SeqCst) != started,
+    };
+    ACTIVE_MEASUREMENTS.fetch_sub(1, Ordering::SeqCst);
+
+    (result, Some(usage))
+}
@@ -0,0 +1,95 @@
+use std::hint::black_box;
+use std::sync::Barrier;
+use std::thread;
+
+use crate::memory::{is_tracking, measure, CountingAllocator, MemoryUsage};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Size of the allocations of the tests, large enough to stand out from the allocations of the other tests
+const SIZE: usize = 1 << 24;
+
+/// Allocate `SIZE` bytes and free them
+fn allocate() {
+    black_box(vec![1u8; SIZE]);
+}
+
+#[test]
+fn test_measure_allocation() {
+    assert!(is_tracking());
+
+    // The other tests may allocate at the same time, their allocations are counted as well
+    let (_, usage) = measure(allocate);
+    let usage = usage.unwrap();
+    assert!(usage.allocations >= 1);
+    assert!(usage.allocated_bytes >= SIZE as u64);
+    assert!(usage.peak_bytes >= SIZE as u64);
+
+    // The returned memory is still allocated at the end of the function
+    let (vector, usage) = measure(|| Vec::<u64>::with_capacity(SIZE));
+    assert!(usage.unwrap().allocated_bytes >= 8 * SIZE as u64);
+    drop(vector);
+}
+
+#[test]
+fn test_measure_then() {
+    let first = MemoryUsage {
+        allocations: 1,
+        allocated_bytes: 10,
+        peak_bytes: 10,
+        concurrent: false,
+    };
+    let second = MemoryUsage {
+        allocations: 2,
+        allocated_bytes: 4,
+        peak_bytes: 3,
+        concurrent: true,
+    };
+    assert_eq!(
+        first.then(second),
+        MemoryUsage {
+            allocations: 3,
+            allocated_bytes: 14,
+            peak_bytes: 10,
+            concurrent: true,
+        }
+    );
+}
+
+#[test]
+fn test_measure_spawned_threads() {
+    // The allocations of the threads run by the function are counted
+    let (_, usage) = measure(|| {
+        thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(allocate);
+            }
+        })
+    });
+    assert!(usage.unwrap().allocated_bytes >= 4 * SIZE as u64);
+}
+
+#[test]
+fn test_measure_concurrently() {
+    // Each measurement counts the allocations of the other one, so both are flagged, whichever starts first
+    let barrier = Barrier::new(2);
+    let usages = thread::scope(|scope| {
+        let measure_overlapping = || {
+            measure(|| {
+                allocate();
+                barrier.wait();
+                allocate();
+            })
+            .1
+            .unwrap()
+        };
+        let first = scope.spawn(measure_overlapping);
+        let second = scope.spawn(measure_overlapping);
+        [first.join().unwrap(), second.join().unwrap()]
+    });
+    for usage in usages {
+        assert!(usage.concurrent);
+        assert!(usage.allocated_bytes >= 2 * SIZE as u64);
+    }
+}
@@ -1,9 +1,151 @@
+use std::fmt::{Display, Formatter};
+
+use num_bigint::BigInt;
+use rand::rngs::StdRng;
+use serde::{Serialize, Serializer};
+
+use crate::assumptions::Assumption;
+use crate::cancellation::CancellationToken;
+use crate::parameters::{Parameter, ParameterError, ParameterValue};
+use crate::parser::ParseError;
+
 pub trait AdventSolution {
-    fn parse(&mut self, _data: String) {}
+    fn parse(&mut self, _data: String) -> Result<(), ParseError> {
+        Ok(())
+    }
 
     fn prepare(&mut self) {}
 
-    fn solve_part_one(&self) -> i128;
+    fn solve_part_one(&self) -> Answer;
+
+    fn solve_part_two(&self) -> Answer;
+
+    /// Solve part one, returning `None` if the token got cancelled before the answer was found.
+    /// Solutions with long-running loops override it to check the token, the other ones ignore it.
+    fn solve_part_one_cancellable(&self, _token: &CancellationToken) -> Option<Answer> {
+        Some(self.solve_part_one())
+    }
+
+    /// Solve part two, returning `None` if the token got cancelled before the answer was found.
+    /// Solutions with long-running loops override it to check the token, the other ones ignore it.
+    fn solve_part_two_cancellable(&self, _token: &CancellationToken) -> Option<Answer> {
+        Some(self.solve_part_two())
+    }
+
+    /// Slow but straightforward implementation of part one, used to check `solve_part_one` on small inputs
+    fn solve_part_one_reference(&self) -> Option<Answer> {
+        None
+    }
+
+    /// Slow but straightforward implementation of part two, used to check `solve_part_two` on small inputs
+    fn solve_part_two_reference(&self) -> Option<Answer> {
+        None
+    }
+
+    /// Generate a random input accepted by `parse`, `size` controls how large the input is
+    fn generate_input(&self, _size: usize, _rng: &mut StdRng) -> Option<String> {
+        None
+    }
+
+    /// Render the state of the solution for debugging: the parsed input, along with the prepared data
+    /// once `prepare` was called. `None` if the solution cannot be rendered.
+    fn render(&self) -> Option<String> {
+        None
+    }
+
+    /// Check the properties of the parsed input that the solution relies on
+    fn check_assumptions(&self) -> Vec<Assumption> {
+        vec![]
+    }
+
+    /// Parameters of the puzzle that can be changed at runtime, with their default value
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![]
+    }
+
+    /// Change the value of a parameter. Only called with a parameter returned by `parameters`
+    /// and a value of the same type as its default, returns an error if the solution does not support the value.
+    fn set_parameter(&mut self, _name: &str, _value: ParameterValue) -> Result<(), ParameterError> {
+        Ok(())
+    }
+
+    /// Parse and set the value of a parameter
+    fn configure(&mut self, name: &str, value: &str) -> Result<(), ParameterError> {
+        let parameter = self
+            .parameters()
+            .into_iter()
+            .find(|parameter| parameter.name == name)
+            .ok_or_else(|| ParameterError::Unknown(name.to_string()))?;
+        let value = parameter.parse(value)?;
+
+        self.set_parameter(name, value)
+    }
+}
+
+/// Answer of a part of a puzzle
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Answer {
+    Integer(i128),
+    /// Integer that does not fit in an i128
+    BigInteger(BigInt),
+    String(String),
+    /// The part has no solution
+    Unimplemented,
+}
+
+macro_rules! impl_from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    match i128::try_from(value) {
+                        Ok(value) => Answer::Integer(value),
+                        Err(_) => Answer::BigInteger(BigInt::from(value)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl From<BigInt> for Answer {
+    fn from(value: BigInt) -> Self {
+        // Keep a single representation for every integer so that answers can be compared
+        match i128::try_from(&value) {
+            Ok(value) => Answer::Integer(value),
+            Err(_) => Answer::BigInteger(value),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::String(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::String(value.to_string())
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{value}"),
+            Answer::BigInteger(value) => write!(f, "{value}"),
+            Answer::String(value) => write!(f, "{value}"),
+            Answer::Unimplemented => write!(f, "unimplemented"),
+        }
+    }
+}
 
-    fn solve_part_two(&self) -> i128;
+// Answers are serialized as strings, like the expected answers they are compared with
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
 }
@@ -0,0 +1,118 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[cfg(test)]
+mod tests;
+
+/// Value of a puzzle parameter, the variant gives the type of the parameter
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum ParameterValue {
+    Integer(i128),
+    Unsigned(usize),
+}
+
+impl ParameterValue {
+    /// Parse a value with the same type as `self`
+    fn parse_as(&self, value: &str) -> Option<Self> {
+        match self {
+            ParameterValue::Integer(_) => value.parse().ok().map(ParameterValue::Integer),
+            ParameterValue::Unsigned(_) => value.parse().ok().map(ParameterValue::Unsigned),
+        }
+    }
+
+    fn type_name(&self) -> &'static str {
+        match self {
+            ParameterValue::Integer(_) => "an integer",
+            ParameterValue::Unsigned(_) => "a positive integer",
+        }
+    }
+}
+
+impl Display for ParameterValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParameterValue::Integer(value) => write!(f, "{value}"),
+            ParameterValue::Unsigned(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// Named parameter of a puzzle that can be changed at runtime
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Parameter {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub default: ParameterValue,
+}
+
+impl Parameter {
+    pub fn new(name: &'static str, description: &'static str, default: ParameterValue) -> Self {
+        Self {
+            name,
+            description,
+            default,
+        }
+    }
+
+    /// Parse a value of this parameter
+    pub fn parse(&self, value: &str) -> Result<ParameterValue, ParameterError> {
+        self.default
+            .parse_as(value)
+            .ok_or_else(|| ParameterError::InvalidValue {
+                name: self.name.to_string(),
+                value: value.to_string(),
+                expected: self.default.type_name(),
+            })
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum ParameterError {
+    Unknown(String),
+    InvalidValue {
+        name: String,
+        value: String,
+        expected: &'static str,
+    },
+}
+
+impl Display for ParameterError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParameterError::Unknown(name) => write!(f, "unknown parameter `{name}`"),
+            ParameterError::InvalidValue { name, value, expected } => {
+                write!(f, "invalid value `{value}` for parameter `{name}`, expected {expected}")
+            }
+        }
+    }
+}
+
+impl Error for ParameterError {}
+
+/// Parameter assignment given as `name=value`
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ParameterAssignment {
+    pub name: String,
+    pub value: String,
+}
+
+impl Display for ParameterAssignment {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.name, self.value)
+    }
+}
+
+impl FromStr for ParameterAssignment {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.split_once('=') {
+            Some((name, value)) if !name.trim().is_empty() => Ok(Self {
+                name: name.trim().to_string(),
+                value: value.trim().to_string(),
+            }),
+            _ => Err(format!("`{value}` must have the form name=value")),
+        }
+    }
+}
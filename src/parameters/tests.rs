@@ -0,0 +1,145 @@
+use crate::models::{AdventSolution, Answer};
+use crate::parameters::{Parameter, ParameterAssignment, ParameterError, ParameterValue};
+use crate::registry::Registration;
+use crate::runner::{check_parameters, configure};
+
+/// Solution answering the value of its parameters
+struct Stub {
+    offset: i128,
+    steps: usize,
+}
+
+impl Default for Stub {
+    fn default() -> Self {
+        Self { offset: -1, steps: 64 }
+    }
+}
+
+impl AdventSolution for Stub {
+    fn solve_part_one(&self) -> Answer {
+        self.offset.into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        self.steps.into()
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![
+            Parameter::new("offset", "Answer of part one", ParameterValue::Integer(-1)),
+            Parameter::new("steps", "Answer of part two", ParameterValue::Unsigned(64)),
+        ]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: ParameterValue) -> Result<(), ParameterError> {
+        match (name, value) {
+            ("offset", ParameterValue::Integer(value)) => self.offset = value,
+            ("steps", ParameterValue::Unsigned(value)) => self.steps = value,
+            _ => unreachable!(),
+        }
+
+        Ok(())
+    }
+}
+
+/// Solution without parameters
+#[derive(Default)]
+struct Plain;
+
+impl AdventSolution for Plain {
+    fn solve_part_one(&self) -> Answer {
+        Answer::Unimplemented
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        Answer::Unimplemented
+    }
+}
+
+const STUB: Registration = Registration::new::<Stub>(2024, 1, "Stub", [true, true]);
+const PLAIN: Registration = Registration::new::<Plain>(2024, 2, "Plain", [false, false]);
+
+fn assignment(name: &str, value: &str) -> ParameterAssignment {
+    ParameterAssignment {
+        name: name.to_string(),
+        value: value.to_string(),
+    }
+}
+
+#[test]
+fn test_assignment() {
+    assert_eq!("steps=64".parse(), Ok(assignment("steps", "64")));
+    assert_eq!(" steps = 64 ".parse(), Ok(assignment("steps", "64")));
+    assert_eq!("name=a=b".parse(), Ok(assignment("name", "a=b")));
+    assert_eq!("steps=".parse(), Ok(assignment("steps", "")));
+    for value in ["steps", "=64", " =64", ""] {
+        assert_eq!(
+            value.parse::<ParameterAssignment>(),
+            Err(format!("`{value}` must have the form name=value"))
+        );
+    }
+    assert_eq!(assignment("steps", "64").to_string(), "steps=64");
+}
+
+#[test]
+fn test_parse_value() {
+    let integer = Parameter::new("offset", "", ParameterValue::Integer(0));
+    let unsigned = Parameter::new("steps", "", ParameterValue::Unsigned(0));
+    assert_eq!(integer.parse("-12"), Ok(ParameterValue::Integer(-12)));
+    assert_eq!(unsigned.parse("12"), Ok(ParameterValue::Unsigned(12)));
+
+    let error = unsigned.parse("-12").unwrap_err();
+    assert_eq!(
+        error,
+        ParameterError::InvalidValue {
+            name: "steps".to_string(),
+            value: "-12".to_string(),
+            expected: "a positive integer"
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "invalid value `-12` for parameter `steps`, expected a positive integer"
+    );
+    for value in ["", "1.5", "x", "99999999999999999999999999999999999999999"] {
+        assert!(integer.parse(value).is_err(), "{value}");
+    }
+}
+
+#[test]
+fn test_configure() {
+    let mut solution = Stub::default();
+    solution.configure("offset", "5").unwrap();
+    solution.configure("steps", "6").unwrap();
+    assert_eq!((solution.offset, solution.steps), (5, 6));
+
+    let error = solution.configure("unknown", "5").unwrap_err();
+    assert_eq!(error, ParameterError::Unknown("unknown".to_string()));
+    assert_eq!(error.to_string(), "unknown parameter `unknown`");
+    assert!(matches!(
+        solution.configure("steps", "x"),
+        Err(ParameterError::InvalidValue { .. })
+    ));
+    // A rejected value leaves the parameter unchanged
+    assert_eq!(solution.steps, 6);
+}
+
+#[test]
+fn test_configure_several_days() {
+    // The days not declaring a parameter ignore it, but the invalid values are rejected
+    let mut solution = Plain;
+    assert_eq!(configure(&mut solution, &[assignment("steps", "6")]), Ok(()));
+    let mut solution = Stub::default();
+    assert!(configure(&mut solution, &[assignment("steps", "6"), assignment("offset", "x")]).is_err());
+
+    // Every parameter must be declared by one of the days
+    assert_eq!(check_parameters(&[&STUB, &PLAIN], &[assignment("steps", "6")]), Ok(()));
+    assert_eq!(
+        check_parameters(&[&PLAIN], &[assignment("steps", "6")]),
+        Err("none of the selected days has a parameter named `steps`".to_string())
+    );
+    assert_eq!(
+        check_parameters(&[&STUB, &PLAIN], &[assignment("steps", "-6")]),
+        Err("day 1: invalid value `-6` for parameter `steps`, expected a positive integer".to_string())
+    );
+}
@@ -0,0 +1,123 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use nom::error::ErrorKind;
+use nom::IResult;
+
+#[cfg(test)]
+mod tests;
+
+/// Located error returned when a puzzle input cannot be parsed.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct ParseError {
+    pub day: usize,
+    /// Line of the error, starting at 1
+    pub line: usize,
+    /// Column of the error, starting at 1
+    pub column: usize,
+    pub expected: String,
+    /// Content of the offending line
+    pub snippet: String,
+}
+
+impl ParseError {
+    /// Create an error located at the given byte offset of the input.
+    pub fn new(day: usize, input: &str, offset: usize, expected: impl Into<String>) -> Self {
+        let offset = offset.min(input.len());
+        let line_start = input[..offset].rfind('\n').map(|position| position + 1).unwrap_or(0);
+        let line_end = input[offset..]
+            .find('\n')
+            .map(|position| offset + position)
+            .unwrap_or(input.len());
+
+        Self {
+            day,
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            expected: expected.into(),
+            snippet: input[line_start..line_end].trim_end_matches('\r').to_string(),
+        }
+    }
+
+    /// Create an error located at the start of `slice`, that must be a sub-slice of `input`.
+    pub fn at(day: usize, input: &str, slice: &str, expected: impl Into<String>) -> Self {
+        Self::new(day, input, slice.as_ptr() as usize - input.as_ptr() as usize, expected)
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let margin = " ".repeat(self.line.to_string().len());
+
+        writeln!(
+            f,
+            "Unable to parse the input of day {:0>2}: expected {} at line {}, column {}",
+            self.day, self.expected, self.line, self.column
+        )?;
+        writeln!(f, "{margin} |")?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(f, "{margin} | {}^", " ".repeat(self.column - 1))
+    }
+}
+
+impl Error for ParseError {}
+
+fn describe(kind: ErrorKind) -> String {
+    match kind {
+        ErrorKind::Digit => "a number".to_string(),
+        ErrorKind::Alpha => "a letter".to_string(),
+        ErrorKind::AlphaNumeric => "a letter or a number".to_string(),
+        ErrorKind::Space | ErrorKind::MultiSpace => "a space".to_string(),
+        ErrorKind::CrLf => "a line ending".to_string(),
+        ErrorKind::Eof => "the end of the input".to_string(),
+        kind => format!("a valid token ({})", kind.description().to_lowercase()),
+    }
+}
+
+/// Convert the result of a nom parser applied to the whole input into a located result.
+/// The parser must consume the whole input.
+pub fn finish<'a, O>(day: usize, input: &'a str, result: IResult<&'a str, O>) -> Result<O, ParseError> {
+    match result {
+        Ok(("", output)) => Ok(output),
+        Ok((remaining, _)) => Err(ParseError::at(day, input, remaining, "the end of the input")),
+        Err(nom::Err::Error(error) | nom::Err::Failure(error)) => {
+            Err(ParseError::at(day, input, error.input, describe(error.code)))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::new(day, input, input.len(), "more input")),
+    }
+}
+
+/// Check that the input only contains line endings and the characters of `allowed`.
+pub fn check_charset(day: usize, input: &str, allowed: &str) -> Result<(), ParseError> {
+    match input
+        .char_indices()
+        .find(|(_, char)| !allowed.contains(*char) && *char != '\n' && *char != '\r')
+    {
+        None => Ok(()),
+        Some((offset, _)) => Err(ParseError::new(
+            day,
+            input,
+            offset,
+            format!("one of {:?}", allowed.chars().collect::<Vec<_>>()),
+        )),
+    }
+}
+
+/// Check that the input is a non-empty grid where every line has the same width.
+pub fn check_rectangular(day: usize, input: &str) -> Result<(), ParseError> {
+    let mut lines = input.lines();
+    let width = match lines.next() {
+        Some(line) if !line.is_empty() => line.chars().count(),
+        _ => return Err(ParseError::new(day, input, 0, "a non empty grid")),
+    };
+
+    match lines.find(|line| line.chars().count() != width) {
+        None => Ok(()),
+        Some(line) => Err(ParseError::at(
+            day,
+            input,
+            line,
+            format!("a line of {width} characters"),
+        )),
+    }
+}
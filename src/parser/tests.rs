@@ -0,0 +1,82 @@
+use nom::character::complete::{char, digit1, line_ending};
+use nom::multi::separated_list1;
+use nom::IResult;
+
+use crate::parser::{check_charset, check_rectangular, finish, ParseError};
+
+fn parse_numbers(input: &str) -> IResult<&str, Vec<&str>> {
+    separated_list1(line_ending, digit1)(input)
+}
+
+fn location(error: ParseError) -> (usize, usize, String, String) {
+    (error.line, error.column, error.expected, error.snippet)
+}
+
+#[test]
+fn test_parse_error_display() {
+    let input = "12\n3x4\n5";
+    let error = ParseError::new(7, input, 4, "a number");
+    assert_eq!(
+        location(error.clone()),
+        (2, 2, "a number".to_string(), "3x4".to_string())
+    );
+    assert_eq!(
+        error.to_string(),
+        "Unable to parse the input of day 07: expected a number at line 2, column 2\n  |\n2 | 3x4\n  |  ^"
+    );
+}
+
+#[test]
+fn test_finish() {
+    assert_eq!(finish(1, "12\n34", parse_numbers("12\n34")), Ok(vec!["12", "34"]));
+
+    // The parser stops before the end of the input
+    let input = "12\n34\nab";
+    assert_eq!(
+        location(finish(1, input, parse_numbers(input)).unwrap_err()),
+        (2, 3, "the end of the input".to_string(), "34".to_string())
+    );
+
+    // The parser fails, the error is located where it failed
+    let input = "12\n34";
+    let result = char::<_, nom::error::Error<_>>('-')(input).map(|(remaining, _)| (remaining, ()));
+    assert_eq!(
+        location(finish(1, input, result).unwrap_err()),
+        (1, 1, "a valid token (char)".to_string(), "12".to_string())
+    );
+    let input = "ab";
+    assert_eq!(
+        location(finish(1, input, parse_numbers(input)).unwrap_err()),
+        (1, 1, "a number".to_string(), "ab".to_string())
+    );
+
+    // A streaming parser reaches the end of the input, the error is located there
+    let input = "12";
+    let result = nom::character::streaming::digit1::<_, nom::error::Error<_>>(input);
+    assert_eq!(
+        location(finish(1, input, result).unwrap_err()),
+        (1, 3, "more input".to_string(), "12".to_string())
+    );
+}
+
+#[test]
+fn test_check_charset() {
+    assert_eq!(check_charset(3, ".#.\r\n#..\n", ".#"), Ok(()));
+    assert_eq!(
+        location(check_charset(3, ".#.\n#.O\n", ".#").unwrap_err()),
+        (2, 3, "one of ['.', '#']".to_string(), "#.O".to_string())
+    );
+}
+
+#[test]
+fn test_check_rectangular() {
+    assert_eq!(check_rectangular(3, ".#.\n#..\n...\n"), Ok(()));
+    assert_eq!(
+        location(check_rectangular(3, "").unwrap_err()),
+        (1, 1, "a non empty grid".to_string(), "".to_string())
+    );
+    assert_eq!(
+        location(check_rectangular(3, ".#.\n#..\n..\n").unwrap_err()),
+        (3, 1, "a line of 3 characters".to_string(), "..".to_string())
+    );
+}
@@ -0,0 +1,175 @@
+use std::str::FromStr;
+
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::{Cell, Color, ContentArrangement, Table};
+use itertools::Itertools;
+
+use crate::models::AdventSolution;
+use crate::SOLUTIONS;
+
+#[cfg(test)]
+mod tests;
+
+/// Description of a solution
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct Metadata {
+    pub year: usize,
+    pub day: usize,
+    pub title: &'static str,
+    /// Whether each part is implemented
+    pub parts: [bool; 2],
+}
+
+impl Metadata {
+    /// Check if a part, starting at 1, is implemented
+    pub fn is_implemented(&self, part: usize) -> bool {
+        self.parts[part - 1]
+    }
+}
+
+/// Entry of the registry, every day module exposes one as `REGISTRATION`
+#[derive(Debug, Clone, Copy)]
+pub struct Registration {
+    pub metadata: Metadata,
+    pub new_solution: fn() -> Box<dyn AdventSolution>,
+}
+
+impl Registration {
+    pub const fn new<S: AdventSolution + Default + 'static>(
+        year: usize,
+        day: usize,
+        title: &'static str,
+        parts: [bool; 2],
+    ) -> Self {
+        Self {
+            metadata: Metadata {
+                year,
+                day,
+                title,
+                parts,
+            },
+            new_solution: new_solution::<S>,
+        }
+    }
+}
+
+fn new_solution<S: AdventSolution + Default + 'static>() -> Box<dyn AdventSolution> {
+    Box::<S>::default()
+}
+
+/// Every registered solution, sorted by year and day
+pub fn solutions() -> impl Iterator<Item = &'static Registration> {
+    SOLUTIONS.iter().flat_map(|year| year.iter())
+}
+
+/// Years having at least one registered solution, sorted
+pub fn years() -> Vec<usize> {
+    let mut years: Vec<_> = solutions().map(|registration| registration.metadata.year).collect();
+    years.dedup();
+
+    years
+}
+
+pub fn find(year: usize, day: usize) -> Option<&'static Registration> {
+    solutions().find(|registration| registration.metadata.year == year && registration.metadata.day == day)
+}
+
+/// Every registered solution of a year, an error if the year has no solution
+pub fn year_solutions(year: usize) -> Result<Vec<&'static Registration>, String> {
+    let registrations: Vec<_> = solutions()
+        .filter(|registration| registration.metadata.year == year)
+        .collect();
+    if registrations.is_empty() {
+        return Err(format!(
+            "{year} has no solution, use the list command to see the available years"
+        ));
+    }
+
+    Ok(registrations)
+}
+
+/// Registered solutions of the days of a year, an error if one of them has no solution
+pub fn select_days(year: usize, days: &Days) -> Result<Vec<&'static Registration>, String> {
+    days.0
+        .iter()
+        .map(|&day| {
+            find(year, day).ok_or_else(|| {
+                format!("day {day} has no solution for {year}, use the list command to see the available days")
+            })
+        })
+        .collect()
+}
+
+/// Table of the registered solutions of a year, or of every year if `year` is not set.
+/// Returns an error if the year has no solution.
+pub fn solutions_table(year: Option<usize>) -> Result<Table, String> {
+    let registrations = match year {
+        Some(year) => year_solutions(year)?,
+        None => solutions().collect(),
+    };
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec!["Year", "Day", "Title", "Part 1", "Part 2", "Parameters"]);
+    for registration in registrations {
+        let metadata = registration.metadata;
+        let part_cell = |part| {
+            if metadata.is_implemented(part) {
+                Cell::new("implemented").fg(Color::Green)
+            } else {
+                Cell::new("unimplemented").fg(Color::DarkGrey)
+            }
+        };
+        table.add_row(vec![
+            Cell::new(metadata.year),
+            Cell::new(metadata.day),
+            Cell::new(metadata.title),
+            part_cell(1),
+            part_cell(2),
+            Cell::new(
+                (registration.new_solution)()
+                    .parameters()
+                    .iter()
+                    .map(|parameter| format!("{}={}", parameter.name, parameter.default))
+                    .join("\n"),
+            ),
+        ]);
+    }
+
+    Ok(table)
+}
+
+/// Sorted list of days, parsed from a comma separated list of days and ranges such as `3,5,10-14`
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Days(pub Vec<usize>);
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let parse_day = |day: &str| match day.trim().parse::<usize>() {
+            Ok(parsed) if (1..=25).contains(&parsed) => Ok(parsed),
+            Ok(_) => Err(format!("`{day}` is not between 1 and 25")),
+            Err(_) => Err(format!("`{day}` is not a valid day")),
+        };
+
+        let mut days = vec![];
+        for item in value.split(',') {
+            match item.split_once('-') {
+                None => days.push(parse_day(item)?),
+                Some((start, end)) => {
+                    let (start, end) = (parse_day(start)?, parse_day(end)?);
+                    if start > end {
+                        return Err(format!("`{item}` is an empty range"));
+                    }
+                    days.extend(start..=end);
+                }
+            }
+        }
+        days.sort_unstable();
+        days.dedup();
+
+        Ok(Self(days))
+    }
+}
@@ -0,0 +1,35 @@
+use crate::registry::{find, solutions_table, years, Days};
+
+#[test]
+fn test_days() {
+    assert_eq!("3".parse(), Ok(Days(vec![3])));
+    assert_eq!("10-12,3, 5,11".parse(), Ok(Days(vec![3, 5, 10, 11, 12])));
+    assert_eq!("3,x".parse::<Days>(), Err("`x` is not a valid day".to_string()));
+    assert_eq!("5-3".parse::<Days>(), Err("`5-3` is an empty range".to_string()));
+    assert_eq!("0".parse::<Days>(), Err("`0` is not between 1 and 25".to_string()));
+    assert_eq!(
+        "1-999999999999".parse::<Days>(),
+        Err("`999999999999` is not between 1 and 25".to_string())
+    );
+}
+
+#[test]
+fn test_find() {
+    // The registered years are listed once each, in order
+    let years = years();
+    assert!(years.contains(&2023));
+    assert!(years.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(find(2023, 7).map(|registration| registration.metadata.day), Some(7));
+    assert!(find(2023, 26).is_none());
+    assert!(find(2022, 7).is_none());
+}
+
+#[test]
+fn test_solutions_table() {
+    assert!(solutions_table(Some(2023)).is_ok());
+    assert!(solutions_table(None).is_ok());
+    assert_eq!(
+        solutions_table(Some(2022)).err(),
+        Some("2022 has no solution, use the list command to see the available years".to_string())
+    );
+}
@@ -0,0 +1,798 @@
+use std::any::Any;
+use std::cell;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::{self, Write};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use comfy_table::presets::UTF8_FULL;
+use comfy_table::{Cell, Color, ContentArrangement, Table};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rayon::prelude::*;
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+
+use crate::assumptions::Assumption;
+use crate::cancellation::CancellationToken;
+use crate::examples::{Example, Manifest};
+use crate::memory::{measure, MemoryUsage};
+use crate::models::{AdventSolution, Answer};
+use crate::parameters::{ParameterAssignment, ParameterError};
+use crate::parser::ParseError;
+use crate::registry::Registration;
+
+#[cfg(test)]
+mod tests;
+
+/// Parts of a puzzle to run, parsed from `1`, `2` or `both`
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+pub enum Part {
+    One,
+    Two,
+    #[default]
+    Both,
+}
+
+impl Part {
+    /// Check if a part, starting at 1, must be run
+    pub fn includes(self, part: usize) -> bool {
+        match self {
+            Part::One => part == 1,
+            Part::Two => part == 2,
+            Part::Both => true,
+        }
+    }
+
+    /// Part covering the selected parts, `None` if no part is selected
+    pub fn from_parts(one: bool, two: bool) -> Option<Self> {
+        match (one, two) {
+            (true, true) => Some(Part::Both),
+            (true, false) => Some(Part::One),
+            (false, true) => Some(Part::Two),
+            (false, false) => None,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            "both" => Ok(Part::Both),
+            _ => Err(format!("`{value}` is not a part, expected 1, 2 or both")),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+            Part::Both => write!(f, "both"),
+        }
+    }
+}
+
+/// Outcome of a part of a solution
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum PartSolution {
+    Solved(Answer),
+    /// Message of the panic that occurred while computing the part
+    Panicked(String),
+    /// The part was not selected
+    Skipped,
+    /// The part has no solution
+    Unimplemented,
+    /// The time budget of the day was exhausted before the part was solved
+    TimedOut,
+}
+
+impl PartSolution {
+    fn from_result(result: Result<Option<Answer>, String>) -> Self {
+        match result {
+            Ok(Some(Answer::Unimplemented)) => PartSolution::Unimplemented,
+            Ok(Some(solution)) => PartSolution::Solved(solution),
+            Ok(None) => PartSolution::TimedOut,
+            Err(message) => PartSolution::Panicked(message),
+        }
+    }
+
+    /// Status of the part in the serialized reports, separate from the answer
+    fn status(&self) -> &'static str {
+        match self {
+            PartSolution::Solved(_) => "solved",
+            PartSolution::Panicked(_) => "panicked",
+            PartSolution::Skipped => "skipped",
+            PartSolution::Unimplemented => "unimplemented",
+            PartSolution::TimedOut => "timed_out",
+        }
+    }
+
+    fn cell(&self, matches_expected: Option<bool>) -> Cell {
+        match (self, matches_expected) {
+            (PartSolution::Panicked(_), _) | (_, Some(false)) => Cell::new(self).fg(Color::Red),
+            (PartSolution::TimedOut, _) => Cell::new(self).fg(Color::Yellow),
+            (PartSolution::Unimplemented, _) => Cell::new(self).fg(Color::DarkGrey),
+            (_, Some(true)) => Cell::new(self).fg(Color::Green),
+            _ => Cell::new(self),
+        }
+    }
+}
+
+impl Display for PartSolution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartSolution::Solved(solution) => write!(f, "{solution}"),
+            PartSolution::Panicked(message) => write!(f, "panicked: {message}"),
+            PartSolution::Skipped => write!(f, "-"),
+            PartSolution::Unimplemented => write!(f, "unimplemented"),
+            PartSolution::TimedOut => write!(f, "timed out"),
+        }
+    }
+}
+
+// Only the answer is serialized, the other outcomes are told by the status of the part
+impl Serialize for PartSolution {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            PartSolution::Solved(answer) => answer.serialize(serializer),
+            _ => serializer.serialize_none(),
+        }
+    }
+}
+
+/// Names of the serialized fields of a report other than the memory usage of the phases, which is serialized before
+/// `memory_concurrent`. The tests check that they are the fields written by `serialize`.
+const REPORT_FIELDS: [&str; 15] = [
+    "year",
+    "day",
+    "input",
+    "part_01_sol",
+    "part_02_sol",
+    "part_01_status",
+    "part_02_status",
+    "part_01_expected",
+    "part_02_expected",
+    "parse_time",
+    "prep_time",
+    "part_01_time",
+    "part_02_time",
+    "total_time",
+    "memory_concurrent",
+];
+
+/// Names of the serialized memory fields of every phase
+const MEMORY_FIELDS: [[&str; 3]; 4] = [
+    ["parse_allocations", "parse_allocated_bytes", "parse_peak_bytes"],
+    ["prep_allocations", "prep_allocated_bytes", "prep_peak_bytes"],
+    ["part_01_allocations", "part_01_allocated_bytes", "part_01_peak_bytes"],
+    ["part_02_allocations", "part_02_allocated_bytes", "part_02_peak_bytes"],
+];
+
+/// Answers, phase timings and memory usage of a solution run on an input, every time is in microseconds.
+/// The memory is only measured when `CountingAllocator` is the global allocator.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct RunReport {
+    pub year: usize,
+    pub day: usize,
+    /// Path of the input
+    pub input: String,
+    pub part_01_sol: PartSolution,
+    pub part_02_sol: PartSolution,
+    /// Answers expected for the input, only known for the examples
+    pub part_01_expected: Option<String>,
+    pub part_02_expected: Option<String>,
+    pub parse_time: u128,
+    pub prep_time: u128,
+    pub part_01_time: u128,
+    pub part_02_time: u128,
+    pub total_time: u128,
+    pub parse_memory: Option<MemoryUsage>,
+    pub prep_memory: Option<MemoryUsage>,
+    pub part_01_memory: Option<MemoryUsage>,
+    pub part_02_memory: Option<MemoryUsage>,
+}
+
+impl RunReport {
+    /// Memory used by the whole run, `None` if it was not measured
+    pub fn total_memory(&self) -> Option<MemoryUsage> {
+        [
+            self.parse_memory,
+            self.prep_memory,
+            self.part_01_memory,
+            self.part_02_memory,
+        ]
+        .into_iter()
+        .flatten()
+        .reduce(MemoryUsage::then)
+    }
+
+    /// Check if the solution of a part, starting at 1, is the expected one.
+    /// Returns `None` if the part was not run or its answer is not known.
+    pub fn matches_expected(&self, part: usize) -> Option<bool> {
+        let (solution, expected) = match part {
+            1 => (&self.part_01_sol, &self.part_01_expected),
+            _ => (&self.part_02_sol, &self.part_02_expected),
+        };
+        match (solution, expected) {
+            (PartSolution::Skipped, _) | (_, None) => None,
+            (solution, Some(expected)) => Some(&solution.to_string() == expected),
+        }
+    }
+
+    /// Check if a part panicked or timed out
+    pub fn has_failed_part(&self) -> bool {
+        [&self.part_01_sol, &self.part_02_sol]
+            .into_iter()
+            .any(|solution| matches!(solution, PartSolution::Panicked(_) | PartSolution::TimedOut))
+    }
+}
+
+// The memory usage is flattened since the CSV format does not support nested structures
+impl Serialize for RunReport {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut report =
+            serializer.serialize_struct("RunReport", REPORT_FIELDS.len() + MEMORY_FIELDS.as_flattened().len())?;
+        report.serialize_field("year", &self.year)?;
+        report.serialize_field("day", &self.day)?;
+        report.serialize_field("input", &self.input)?;
+        report.serialize_field("part_01_sol", &self.part_01_sol)?;
+        report.serialize_field("part_02_sol", &self.part_02_sol)?;
+        report.serialize_field("part_01_status", self.part_01_sol.status())?;
+        report.serialize_field("part_02_status", self.part_02_sol.status())?;
+        report.serialize_field("part_01_expected", &self.part_01_expected)?;
+        report.serialize_field("part_02_expected", &self.part_02_expected)?;
+        report.serialize_field("parse_time", &self.parse_time)?;
+        report.serialize_field("prep_time", &self.prep_time)?;
+        report.serialize_field("part_01_time", &self.part_01_time)?;
+        report.serialize_field("part_02_time", &self.part_02_time)?;
+        report.serialize_field("total_time", &self.total_time)?;
+
+        let memories = [
+            self.parse_memory,
+            self.prep_memory,
+            self.part_01_memory,
+            self.part_02_memory,
+        ];
+        for ([allocations, allocated_bytes, peak_bytes], memory) in MEMORY_FIELDS.into_iter().zip(memories) {
+            report.serialize_field(allocations, &memory.map(|memory| memory.allocations))?;
+            report.serialize_field(allocated_bytes, &memory.map(|memory| memory.allocated_bytes))?;
+            report.serialize_field(peak_bytes, &memory.map(|memory| memory.peak_bytes))?;
+        }
+        report.serialize_field(
+            "memory_concurrent",
+            &self.total_memory().map(|memory| memory.concurrent),
+        )?;
+
+        report.end()
+    }
+}
+
+impl Display for RunReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Day {:0>2}, results: {:>14}, {:>14}, \
+            parse_time: {:>10} us, prep_time: {:>10} us, \
+            part_01_time: {:>10} us, part_02_time: {:>10} us",
+            self.day,
+            self.part_01_sol.to_string(),
+            self.part_02_sol.to_string(),
+            self.parse_time,
+            self.prep_time,
+            self.part_01_time,
+            self.part_02_time
+        )
+    }
+}
+
+/// Error preventing a solution from being run
+#[derive(Debug)]
+pub enum RunError {
+    /// The input file cannot be read
+    Input {
+        path: String,
+        error: io::Error,
+    },
+    /// A parameter has an invalid value
+    Parameter {
+        day: usize,
+        error: ParameterError,
+    },
+    Parse(ParseError),
+}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunError::Input { path, error } => write!(f, "Unable to read {path}: {error}"),
+            RunError::Parameter { day, error } => write!(f, "day {day}: {error}"),
+            RunError::Parse(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl Error for RunError {}
+
+impl From<ParseError> for RunError {
+    fn from(error: ParseError) -> Self {
+        RunError::Parse(error)
+    }
+}
+
+thread_local! {
+    /// Whether the current thread runs a function through `catch_panic`
+    static CATCHING_PANIC: cell::Cell<bool> = const { cell::Cell::new(false) };
+}
+
+/// Check if the current thread runs a function through `catch_panic`, whose panics are reported in its result.
+/// A panic hook can use it to only display the other panics.
+pub fn is_catching_panic() -> bool {
+    CATCHING_PANIC.get()
+}
+
+/// Run the function, catching any panic and returning its message instead
+pub fn catch_panic<T>(function: impl FnOnce() -> T) -> Result<T, String> {
+    let was_catching = CATCHING_PANIC.replace(true);
+    let result = catch_unwind(AssertUnwindSafe(function));
+    CATCHING_PANIC.set(was_catching);
+
+    result.map_err(|payload: Box<dyn Any + Send>| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown panic".to_string())
+    })
+}
+
+/// Set the parameters declared by the solution, the other ones are ignored
+pub fn configure(solution: &mut dyn AdventSolution, parameters: &[ParameterAssignment]) -> Result<(), ParameterError> {
+    for parameter in parameters {
+        match solution.configure(&parameter.name, &parameter.value) {
+            Ok(()) | Err(ParameterError::Unknown(_)) => {}
+            Err(error) => return Err(error),
+        }
+    }
+
+    Ok(())
+}
+
+/// Check that every parameter is declared by at least one of the solutions and has a valid value for each of them
+pub fn check_parameters(registrations: &[&Registration], parameters: &[ParameterAssignment]) -> Result<(), String> {
+    for registration in registrations {
+        configure((registration.new_solution)().as_mut(), parameters)
+            .map_err(|error| format!("day {}: {error}", registration.metadata.day))?;
+    }
+    for parameter in parameters {
+        let declared = registrations.iter().any(|registration| {
+            (registration.new_solution)()
+                .parameters()
+                .iter()
+                .any(|declared| declared.name == parameter.name)
+        });
+        if !declared {
+            return Err(format!(
+                "none of the selected days has a parameter named `{}`",
+                parameter.name
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Path of the input of a day, in `inputs/<year>` for the real inputs and in `input_examples/<year>` otherwise
+pub fn input_path(year: usize, day: usize, use_real_input: bool) -> String {
+    if use_real_input {
+        format!("inputs/{year}/day{:0>2}", day)
+    } else {
+        format!("input_examples/{year}/day{:0>2}", day)
+    }
+}
+
+/// Read an input file, `-` reads the standard input
+pub fn read_input(path: &str) -> Result<String, RunError> {
+    if path == "-" {
+        io::read_to_string(io::stdin())
+    } else {
+        fs::read_to_string(path)
+    }
+    .map_err(|error| RunError::Input {
+        path: path.to_string(),
+        error,
+    })
+}
+
+/// Solve a part unless the token is already cancelled, a part solved once the token got cancelled is timed out
+fn solve_part(token: &CancellationToken, solve: impl FnOnce() -> Option<Answer>) -> Result<Option<Answer>, String> {
+    if token.is_cancelled() {
+        return Ok(None);
+    }
+    let answer = catch_panic(solve)?;
+
+    Ok(answer.filter(|_| !token.is_cancelled()))
+}
+
+/// Run a solution on an input, `input_path` is only used in the report.
+/// Panics of the solution are caught and reported as the answer of the parts they prevent from being solved.
+/// With a `timeout`, the parts not solved before it elapses from the start of the run are reported as timed out:
+/// the solutions checking their cancellation token give up, the other ones run to the end. The parsing and the
+/// preparation are not covered by the timeout: they are never interrupted nor reported as timed out, even though the
+/// time they take is taken from the budget of the parts.
+pub fn run_day(
+    registration: &Registration,
+    part: Part,
+    parameters: &[ParameterAssignment],
+    timeout: Option<Duration>,
+    input_path: String,
+    input: String,
+) -> Result<RunReport, RunError> {
+    let metadata = registration.metadata;
+    let mut solution = (registration.new_solution)();
+    configure(solution.as_mut(), parameters).map_err(|error| RunError::Parameter {
+        day: metadata.day,
+        error,
+    })?;
+
+    let token = timeout.map(CancellationToken::with_timeout).unwrap_or_default();
+
+    // Parse the data
+    let now = Instant::now();
+    let (parsed, parse_memory) = measure(|| catch_panic(|| solution.parse(input)));
+    let parse_time = now.elapsed().as_micros();
+    let mut ready = match parsed {
+        Ok(result) => result.map(|_| Ok(()))?,
+        Err(message) => Err(message),
+    };
+
+    // Prepare the parsed_data
+    let now = Instant::now();
+    let mut prep_memory = None;
+    if ready.is_ok() {
+        (ready, prep_memory) = measure(|| catch_panic(|| solution.prepare()));
+    }
+    let prep_time = now.elapsed().as_micros();
+
+    // Solve part one
+    let now = Instant::now();
+    let (part_01_sol, part_01_memory) = if !part.includes(1) {
+        (PartSolution::Skipped, None)
+    } else if !metadata.is_implemented(1) {
+        (PartSolution::Unimplemented, None)
+    } else {
+        let (result, memory) = measure(|| {
+            ready
+                .clone()
+                .and_then(|_| solve_part(&token, || solution.solve_part_one_cancellable(&token)))
+        });
+        (PartSolution::from_result(result), memory)
+    };
+    let part_01_time = now.elapsed().as_micros();
+
+    // Solve part two
+    let now = Instant::now();
+    let (part_02_sol, part_02_memory) = if !part.includes(2) {
+        (PartSolution::Skipped, None)
+    } else if !metadata.is_implemented(2) {
+        (PartSolution::Unimplemented, None)
+    } else {
+        let (result, memory) =
+            measure(|| ready.and_then(|_| solve_part(&token, || solution.solve_part_two_cancellable(&token))));
+        (PartSolution::from_result(result), memory)
+    };
+    let part_02_time = now.elapsed().as_micros();
+
+    Ok(RunReport {
+        year: metadata.year,
+        day: metadata.day,
+        input: input_path,
+        part_01_sol,
+        part_02_sol,
+        part_01_expected: None,
+        part_02_expected: None,
+        parse_time,
+        prep_time,
+        part_01_time,
+        part_02_time,
+        total_time: parse_time + prep_time + part_01_time + part_02_time,
+        parse_memory,
+        prep_memory,
+        part_01_memory,
+        part_02_memory,
+    })
+}
+
+/// Read the input file at `path` then run the solution on it
+pub fn run_file(
+    registration: &Registration,
+    part: Part,
+    parameters: &[ParameterAssignment],
+    timeout: Option<Duration>,
+    path: &str,
+) -> Result<RunReport, RunError> {
+    let input = read_input(path)?;
+    run_day(registration, part, parameters, timeout, path.to_string(), input)
+}
+
+/// Run an example of a solution with the given parameters, the parameters of the example take precedence
+/// since its expected answers depend on them. Only the parts of `part` having an expected answer in the example are run.
+pub fn run_example(
+    registration: &Registration,
+    example: &Example,
+    part: Part,
+    parameters: &[ParameterAssignment],
+    timeout: Option<Duration>,
+) -> Result<RunReport, RunError> {
+    let [part_01_expected, part_02_expected] = example.answers.clone();
+    let part = Part::from_parts(
+        part.includes(1) && part_01_expected.is_some(),
+        part.includes(2) && part_02_expected.is_some(),
+    );
+    let Some(part) = part else {
+        return Ok(RunReport {
+            year: example.year,
+            day: example.day,
+            input: example.label(),
+            part_01_sol: PartSolution::Skipped,
+            part_02_sol: PartSolution::Skipped,
+            part_01_expected,
+            part_02_expected,
+            parse_time: 0,
+            prep_time: 0,
+            part_01_time: 0,
+            part_02_time: 0,
+            total_time: 0,
+            parse_memory: None,
+            prep_memory: None,
+            part_01_memory: None,
+            part_02_memory: None,
+        });
+    };
+
+    let parameters: Vec<_> = parameters.iter().chain(&example.parameters).cloned().collect();
+    let input = read_input(&example.path())?;
+    let report = run_day(registration, part, &parameters, timeout, example.label(), input)?;
+
+    Ok(RunReport {
+        part_01_expected,
+        part_02_expected,
+        ..report
+    })
+}
+
+/// Run the function on every item, running up to `jobs` items concurrently.
+/// The results are in the same order as the items.
+fn run_parallel<T: Sync, R: Send>(items: &[T], jobs: usize, function: impl Fn(&T) -> R + Sync + Send) -> Vec<R> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs.max(1))
+        .build()
+        .unwrap();
+
+    pool.install(|| items.par_iter().map(function).collect())
+}
+
+/// Run several solutions on their own input, solving up to `jobs` days concurrently.
+/// The results are in the same order as the registrations.
+pub fn run_days(
+    registrations: &[&Registration],
+    part: Part,
+    parameters: &[ParameterAssignment],
+    timeout: Option<Duration>,
+    use_real_input: bool,
+    jobs: usize,
+) -> Vec<Result<RunReport, RunError>> {
+    run_parallel(registrations, jobs, |registration| {
+        let path = input_path(registration.metadata.year, registration.metadata.day, use_real_input);
+        run_file(registration, part, parameters, timeout, &path)
+    })
+}
+
+/// Run several solutions on every example of the manifest, solving up to `jobs` examples concurrently.
+/// A day without example in the manifest is run on `input_examples/<year>/dayNN`, without expected answers.
+/// The results are in the order of the registrations then of the manifest.
+pub fn run_examples(
+    registrations: &[&Registration],
+    manifest: &Manifest,
+    part: Part,
+    parameters: &[ParameterAssignment],
+    timeout: Option<Duration>,
+    jobs: usize,
+) -> Vec<Result<RunReport, RunError>> {
+    let runs: Vec<_> = registrations
+        .iter()
+        .flat_map(|registration| {
+            let examples: Vec<_> = manifest.examples(registration.metadata.day).map(Some).collect();
+            if examples.is_empty() {
+                vec![(registration, None)]
+            } else {
+                examples.into_iter().map(|example| (registration, example)).collect()
+            }
+        })
+        .collect();
+
+    run_parallel(&runs, jobs, |(registration, example)| match example {
+        Some(example) => run_example(registration, example, part, parameters, timeout),
+        None => run_file(
+            registration,
+            part,
+            parameters,
+            timeout,
+            &input_path(registration.metadata.year, registration.metadata.day, false),
+        ),
+    })
+}
+
+/// Split the results of several runs into the reports of the successful runs and the errors of the other ones
+pub fn split_results(results: Vec<Result<RunReport, RunError>>) -> (Vec<RunReport>, Vec<RunError>) {
+    let mut errors = vec![];
+    let reports = results
+        .into_iter()
+        .filter_map(|result| result.map_err(|error| errors.push(error)).ok())
+        .collect();
+
+    (reports, errors)
+}
+
+/// Number of answers of the reports differing from the expected ones
+pub fn count_mismatches(reports: &[RunReport]) -> usize {
+    reports
+        .iter()
+        .flat_map(|report| [report.matches_expected(1), report.matches_expected(2)])
+        .filter(|matches| *matches == Some(false))
+        .count()
+}
+
+/// Phase of a solution after which its data is rendered, parsed from `parsed` or `prepared`
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default)]
+pub enum Stage {
+    #[default]
+    Parsed,
+    Prepared,
+}
+
+impl FromStr for Stage {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "parsed" => Ok(Stage::Parsed),
+            "prepared" => Ok(Stage::Prepared),
+            _ => Err(format!("`{value}` is not a stage, expected parsed or prepared")),
+        }
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parsed => write!(f, "parsed"),
+            Stage::Prepared => write!(f, "prepared"),
+        }
+    }
+}
+
+/// Render the data of a solution once the input is parsed or prepared, `None` if the solution cannot be rendered
+pub fn inspect(
+    registration: &Registration,
+    parameters: &[ParameterAssignment],
+    stage: Stage,
+    input: String,
+) -> Result<Option<String>, RunError> {
+    let mut solution = (registration.new_solution)();
+    configure(solution.as_mut(), parameters).map_err(|error| RunError::Parameter {
+        day: registration.metadata.day,
+        error,
+    })?;
+    solution.parse(input)?;
+    if stage == Stage::Prepared {
+        solution.prepare();
+    }
+
+    Ok(solution.render())
+}
+
+/// Check the assumptions a solution makes on an input, without solving it
+pub fn check_input(registration: &Registration, input: String) -> Result<Vec<Assumption>, RunError> {
+    let mut solution = (registration.new_solution)();
+    solution.parse(input)?;
+
+    Ok(solution.check_assumptions())
+}
+
+/// Generate a random input for a solution from a seed, `None` if the solution does not have an input generator
+pub fn generate_input(registration: &Registration, size: usize, seed: u64) -> Option<String> {
+    (registration.new_solution)().generate_input(size, &mut StdRng::seed_from_u64(seed))
+}
+
+/// Table of the answers and timings of several runs.
+/// The memory used by each run is added when it was measured, the details per phase are only serialized.
+/// The memory of a run measured concurrently with other runs is displayed in yellow, since it includes their
+/// allocations.
+pub fn reports_table(reports: &[RunReport]) -> Table {
+    let with_memory = reports.iter().any(|report| report.total_memory().is_some());
+    let mut header = vec![
+        "Day",
+        "Input",
+        "Part 1 solution",
+        "Part 2 solution",
+        "Parse time",
+        "Prep time",
+        "Part 1 time",
+        "Part 2 time",
+        "Tot time",
+    ];
+    if with_memory {
+        header.extend(["Allocations", "Allocated bytes", "Peak bytes"]);
+    }
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(header);
+    for report in reports {
+        let mut row = vec![
+            Cell::new(report.day),
+            Cell::new(&report.input),
+            report.part_01_sol.cell(report.matches_expected(1)),
+            report.part_02_sol.cell(report.matches_expected(2)),
+            Cell::new(report.parse_time),
+            Cell::new(report.prep_time),
+            Cell::new(report.part_01_time),
+            Cell::new(report.part_02_time),
+            Cell::new(report.total_time),
+        ];
+        if with_memory {
+            let memory = report.total_memory().unwrap_or_default();
+            let cell = |value: u64| match memory.concurrent {
+                true => Cell::new(value).fg(Color::Yellow),
+                false => Cell::new(value),
+            };
+            row.extend([
+                cell(memory.allocations),
+                cell(memory.allocated_bytes),
+                cell(memory.peak_bytes),
+            ]);
+        }
+        table.add_row(row);
+    }
+
+    table
+}
+
+/// Serialize the reports as a JSON array
+pub fn reports_to_json(reports: &[RunReport]) -> String {
+    serde_json::to_string_pretty(reports).unwrap()
+}
+
+/// Names of the CSV columns, in the order the fields of the reports are serialized
+fn csv_header() -> Vec<&'static str> {
+    let (memory_concurrent, fields) = REPORT_FIELDS.split_last().unwrap();
+    fields
+        .iter()
+        .chain(MEMORY_FIELDS.as_flattened())
+        .chain([memory_concurrent])
+        .copied()
+        .collect()
+}
+
+/// Write the reports as CSV, one line per report after a header line that is written even without reports
+pub fn write_reports_csv(reports: &[RunReport], writer: impl Write) -> Result<(), csv::Error> {
+    let mut writer = csv::WriterBuilder::new().has_headers(false).from_writer(writer);
+    writer.write_record(csv_header())?;
+    for report in reports {
+        writer.serialize(report)?;
+    }
+    writer.flush()?;
+
+    Ok(())
+}
@@ -0,0 +1,231 @@
+use std::time::Duration;
+
+use crate::cancellation::CancellationToken;
+use crate::models::{AdventSolution, Answer};
+use crate::parser::ParseError;
+use crate::registry::Registration;
+use crate::runner::{
+    catch_panic, count_mismatches, csv_header, inspect, is_catching_panic, reports_to_json, run_day, write_reports_csv,
+    Part, PartSolution, RunError, RunReport, Stage,
+};
+
+/// Solution whose behaviour is chosen by its input: `panic` panics in part one, `unimplemented` has no answer for
+/// part one, `loop` runs part one until cancelled, `invalid` cannot be parsed and `hidden` cannot be rendered.
+/// Part two always answers 2.
+#[derive(Default)]
+struct Stub {
+    input: String,
+    prepared: bool,
+}
+
+impl AdventSolution for Stub {
+    fn parse(&mut self, data: String) -> Result<(), ParseError> {
+        if data == "invalid" {
+            return Err(ParseError::new(1, &data, 0, "a valid input"));
+        }
+        self.input = data;
+
+        Ok(())
+    }
+
+    fn prepare(&mut self) {
+        self.prepared = true;
+    }
+
+    fn solve_part_one(&self) -> Answer {
+        match self.input.as_str() {
+            "panic" => panic!("part one panicked"),
+            "unimplemented" => Answer::Unimplemented,
+            input => input.len().into(),
+        }
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        2.into()
+    }
+
+    fn solve_part_one_cancellable(&self, token: &CancellationToken) -> Option<Answer> {
+        if self.input == "loop" {
+            while !token.is_cancelled() {}
+            return None;
+        }
+
+        Some(self.solve_part_one())
+    }
+
+    fn render(&self) -> Option<String> {
+        let stage = if self.prepared { "prepared" } else { "parsed" };
+        (self.input != "hidden").then(|| format!("{stage} {}", self.input))
+    }
+}
+
+const STUB: Registration = Registration::new::<Stub>(2024, 3, "Stub", [true, true]);
+
+fn run(registration: &Registration, part: Part, timeout: Option<Duration>, input: &str) -> RunReport {
+    run_day(registration, part, &[], timeout, "stub".to_string(), input.to_string()).unwrap()
+}
+
+fn solutions(report: &RunReport) -> (PartSolution, PartSolution) {
+    (report.part_01_sol.clone(), report.part_02_sol.clone())
+}
+
+#[test]
+fn test_run_day() {
+    let report = run(&STUB, Part::Both, None, "input");
+    assert_eq!((report.year, report.day, report.input.as_str()), (2024, 3, "stub"));
+    assert_eq!(
+        solutions(&report),
+        (
+            PartSolution::Solved(Answer::Integer(5)),
+            PartSolution::Solved(Answer::Integer(2))
+        )
+    );
+    assert_eq!(
+        report.total_time,
+        report.parse_time + report.prep_time + report.part_01_time + report.part_02_time
+    );
+    assert!(!report.has_failed_part());
+}
+
+#[test]
+fn test_run_day_panic() {
+    // The panic only prevents part one from being solved
+    let report = run(&STUB, Part::Both, None, "panic");
+    assert_eq!(
+        solutions(&report),
+        (
+            PartSolution::Panicked("part one panicked".to_string()),
+            PartSolution::Solved(Answer::Integer(2))
+        )
+    );
+    assert!(report.has_failed_part());
+    assert_eq!(
+        catch_panic(|| panic!("{} panicked", "formatted")),
+        Err::<(), _>("formatted panicked".to_string())
+    );
+
+    // The panic hook can tell the caught panics from the other ones, even when they are nested
+    assert!(!is_catching_panic());
+    assert_eq!(
+        catch_panic(|| (is_catching_panic(), catch_panic(is_catching_panic), is_catching_panic())),
+        Ok((true, Ok(true), true))
+    );
+    assert_eq!(catch_panic(|| panic!("caught")), Err::<(), _>("caught".to_string()));
+    assert!(!is_catching_panic());
+}
+
+#[test]
+fn test_run_day_skipped() {
+    let report = run(&STUB, Part::Two, None, "input");
+    assert_eq!(
+        solutions(&report),
+        (PartSolution::Skipped, PartSolution::Solved(Answer::Integer(2)))
+    );
+    assert_eq!(report.matches_expected(1), None);
+}
+
+#[test]
+fn test_run_day_unimplemented() {
+    // Part one returns no answer, part two is declared unimplemented and not run
+    let registration = Registration::new::<Stub>(2024, 3, "Stub", [true, false]);
+    let report = run(&registration, Part::Both, None, "unimplemented");
+    assert_eq!(
+        solutions(&report),
+        (PartSolution::Unimplemented, PartSolution::Unimplemented)
+    );
+    assert!(!report.has_failed_part());
+}
+
+#[test]
+fn test_run_day_timeout() {
+    let report = run(&STUB, Part::Both, Some(Duration::from_millis(10)), "loop");
+    assert_eq!(solutions(&report), (PartSolution::TimedOut, PartSolution::TimedOut));
+    assert!(report.has_failed_part());
+
+    let report = run(&STUB, Part::Both, Some(Duration::from_secs(60)), "input");
+    assert_eq!(
+        solutions(&report),
+        (
+            PartSolution::Solved(Answer::Integer(5)),
+            PartSolution::Solved(Answer::Integer(2))
+        )
+    );
+}
+
+#[test]
+fn test_run_day_parse_error() {
+    let result = run_day(&STUB, Part::Both, &[], None, "stub".to_string(), "invalid".to_string());
+    assert!(matches!(result, Err(RunError::Parse(error)) if error.day == 1));
+}
+
+#[test]
+fn test_count_mismatches() {
+    let mut report = run(&STUB, Part::Both, None, "input");
+    report.part_01_expected = Some("5".to_string());
+    report.part_02_expected = Some("3".to_string());
+    assert_eq!(
+        (report.matches_expected(1), report.matches_expected(2)),
+        (Some(true), Some(false))
+    );
+    assert_eq!(count_mismatches(&[report.clone(), report]), 2);
+}
+
+#[test]
+fn test_reports_to_json() {
+    // The solutions and the expected answers have the same type
+    let mut report = run(&STUB, Part::One, None, "input");
+    report.part_01_expected = Some("5".to_string());
+    let json: serde_json::Value = serde_json::from_str(&reports_to_json(&[report])).unwrap();
+    assert_eq!(json[0]["part_01_sol"], json[0]["part_01_expected"]);
+    assert_eq!(json[0]["part_01_sol"], "5");
+    assert_eq!(json[0]["part_01_status"], "solved");
+    assert_eq!(json[0]["part_02_sol"], serde_json::Value::Null);
+    assert_eq!(json[0]["part_02_status"], "skipped");
+
+    // A failed part has no answer
+    let report = run(&STUB, Part::Both, None, "panic");
+    let json: serde_json::Value = serde_json::from_str(&reports_to_json(&[report])).unwrap();
+    assert_eq!(json[0]["part_01_sol"], serde_json::Value::Null);
+    assert_eq!(json[0]["part_01_status"], "panicked");
+}
+
+#[test]
+fn test_write_reports_csv() {
+    // The header is written even without reports
+    let mut csv = vec![];
+    write_reports_csv(&[], &mut csv).unwrap();
+    let header = csv_header().join(",");
+    assert_eq!(String::from_utf8(csv).unwrap(), format!("{header}\n"));
+
+    let report = run(&STUB, Part::Both, None, "input");
+    let mut csv = vec![];
+    write_reports_csv(&[report.clone(), report], &mut csv).unwrap();
+    let csv = String::from_utf8(csv).unwrap();
+    let lines: Vec<_> = csv.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0], header);
+    assert!(lines[1].starts_with("2024,3,stub,5,2,solved,solved,,,"));
+
+    // Which is the header the serializer writes
+    let mut writer = csv::Writer::from_writer(vec![]);
+    writer.serialize(run(&STUB, Part::Both, None, "input")).unwrap();
+    let csv = String::from_utf8(writer.into_inner().unwrap()).unwrap();
+    assert_eq!(csv.lines().next(), Some(header.as_str()));
+}
+
+#[test]
+fn test_inspect() {
+    let inspected = |stage, input: &str| inspect(&STUB, &[], stage, input.to_string()).unwrap();
+    assert_eq!(inspected(Stage::Parsed, "input"), Some("parsed input".to_string()));
+    assert_eq!(inspected(Stage::Prepared, "input"), Some("prepared input".to_string()));
+    assert_eq!(inspected(Stage::Prepared, "hidden"), None);
+    assert!(matches!(
+        inspect(&STUB, &[], Stage::Parsed, "invalid".to_string()),
+        Err(RunError::Parse(error)) if error.day == 1
+    ));
+    assert_eq!("prepared".parse(), Ok(Stage::Prepared));
+    assert_eq!(
+        "solved".parse::<Stage>(),
+        Err("`solved` is not a stage, expected parsed or prepared".to_string())
+    );
+}
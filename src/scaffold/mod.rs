@@ -0,0 +1,231 @@
+use std::fs::{self, OpenOptions};
+use std::io::{Error, ErrorKind};
+use std::path::Path;
+
+use crate::examples::{manifest_path, Manifest, UNKNOWN_ANSWER};
+
+#[cfg(test)]
+mod tests;
+
+/// Files of the `dayxx` template module. The tests of the template check its own example, a new day checks the
+/// examples of the manifest of its year instead.
+const TEMPLATE: [(&str, &str); 6] = [
+    ("mod.rs", include_str!("../dayxx/mod.rs")),
+    ("generator.rs", include_str!("../dayxx/generator.rs")),
+    ("parser.rs", include_str!("../dayxx/parser.rs")),
+    ("logic.rs", include_str!("../dayxx/logic.rs")),
+    ("models.rs", include_str!("../dayxx/models.rs")),
+    ("tests.rs", TESTS),
+];
+
+/// Tests of a new day, written for the `dayxx` template like the other files
+const TESTS: &str = "use crate::common::{test_examples_common, test_generated_input_common};
+use crate::dayxx::{DayXX, REGISTRATION};
+
+#[test]
+fn test_examples() {
+    test_examples_common(&REGISTRATION);
+}
+
+#[test]
+fn test_generated_input() {
+    test_generated_input_common::<DayXX>();
+}
+";
+
+/// Content of the module of a year without any day
+const EMPTY_YEAR: &str = "register_days!();\n";
+
+/// Create the `dayNN` module of a day from the `dayxx` template in the `yearYYYY` module of the crate located at
+/// `root`, creating and registering the year module in `lib.rs` if needed. The day is registered in its year module,
+/// its empty input files are created and unknown answers are added to the example manifest of the year, so that the
+/// tests of the day fail until its example answers are filled in.
+/// An existing day is never overwritten.
+pub fn new_day(root: &Path, year: usize, day: usize) -> Result<(), Error> {
+    if !(1..=25).contains(&day) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("day {day} is not between 1 and 25"),
+        ));
+    }
+    let year_module = format!("year{year}");
+    let module = format!("day{day:0>2}");
+    let year_path = root.join("src").join(&year_module);
+    let module_path = year_path.join(&module);
+    if module_path.exists() {
+        return Err(Error::new(
+            ErrorKind::AlreadyExists,
+            format!("{} already exists", module_path.display()),
+        ));
+    }
+
+    // Check lib.rs, the year module and the manifest before writing anything so that a failure leaves the crate
+    // untouched
+    let lib_path = root.join("src/lib.rs");
+    let year_mod_path = year_path.join("mod.rs");
+    let (year_mod, lib) = match fs::read_to_string(&year_mod_path) {
+        Err(error) if error.kind() == ErrorKind::NotFound => {
+            let lib = fs::read_to_string(&lib_path)?;
+            (
+                EMPTY_YEAR.to_string(),
+                Some(declare_module(
+                    &register_module(&lib, "register_years!(", &year_module)?,
+                    &year_module,
+                )),
+            )
+        }
+        result => (result?, None),
+    };
+    let year_mod = declare_module(&register_module(&year_mod, "register_days!(", &module)?, &module);
+    let manifest_path = root.join(manifest_path(year));
+    let mut manifest = match fs::read_to_string(&manifest_path) {
+        Err(error) if error.kind() == ErrorKind::NotFound => String::new(),
+        result => result?,
+    };
+    let has_examples = Manifest::parse(&manifest, year)?.examples(day).next().is_some();
+    let files = TEMPLATE
+        .into_iter()
+        .map(|(file, content)| {
+            let content = content
+                .replace("crate::dayxx", &format!("crate::{year_module}::{module}"))
+                .replace("dayxx", &module)
+                .replace("DayXX", &format!("Day{day:0>2}"));
+            let content = match file {
+                "mod.rs" => register_day(&content, year, day)?,
+                _ => content,
+            };
+            Ok((file, content))
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    fs::create_dir_all(&module_path)?;
+    for (file, content) in files {
+        fs::write(module_path.join(file), content)?;
+    }
+    fs::write(&year_mod_path, year_mod)?;
+    if let Some(lib) = lib {
+        fs::write(&lib_path, lib)?;
+    }
+
+    for directory in ["input_examples", "inputs"] {
+        let directory = root.join(directory).join(year.to_string());
+        fs::create_dir_all(&directory)?;
+        match OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(directory.join(&module))
+        {
+            Err(error) if error.kind() != ErrorKind::AlreadyExists => return Err(error),
+            _ => {}
+        }
+    }
+
+    if !has_examples {
+        if !manifest.is_empty() && !manifest.ends_with('\n') {
+            manifest.push('\n');
+        }
+        manifest.push_str(&format!(
+            "{day} 1 {module} {UNKNOWN_ANSWER}\n{day} 2 {module} {UNKNOWN_ANSWER}\n"
+        ));
+        fs::write(manifest_path, manifest)?;
+    }
+
+    Ok(())
+}
+
+/// Set the year and day of the registration in `source`, the first two arguments of its `Registration::new` call
+fn register_day(source: &str, year: usize, day: usize) -> Result<String, Error> {
+    let invalid = || {
+        Error::new(
+            ErrorKind::InvalidData,
+            "unable to find the Registration::new call of the template",
+        )
+    };
+    let call = source.find("Registration::new::<").ok_or_else(invalid)?;
+    let start = call + source[call..].find('(').ok_or_else(invalid)? + 1;
+    let mut arguments = source[start..].splitn(3, ',');
+    let (Some(_), Some(_), Some(rest)) = (arguments.next(), arguments.next(), arguments.next()) else {
+        return Err(invalid());
+    };
+
+    Ok(format!("{}{year}, {day},{rest}", &source[..start]))
+}
+
+/// Add the `pub mod` declaration of a module to `source`, keeping the public module declarations sorted.
+/// The declaration goes above the first declared module coming after it and its attributes, or after the last one.
+fn declare_module(source: &str, module: &str) -> String {
+    fn declared(line: &str) -> Option<&str> {
+        line.strip_prefix("pub mod ")?.strip_suffix(';')
+    }
+
+    let mut lines: Vec<_> = source.lines().collect();
+    let declaration = format!("pub mod {module};");
+    let position = match lines
+        .iter()
+        .position(|line| declared(line).is_some_and(|declared| declared > module))
+    {
+        Some(mut position) => {
+            while position > 0 && lines[position - 1].starts_with("#[") {
+                position -= 1;
+            }
+            position
+        }
+        None => match lines.iter().rposition(|line| declared(line).is_some()) {
+            Some(last) => last + 1,
+            // Without any declaration yet, start a block of declarations at the top of the file
+            None => return format!("{declaration}\n\n{source}"),
+        },
+    };
+
+    lines.insert(position, &declaration);
+    lines.join("\n") + "\n"
+}
+
+/// Add a module to the invocation of the registration macro `register` in `source`, keeping the modules sorted
+fn register_module(source: &str, register: &str, module: &str) -> Result<String, Error> {
+    let invalid = || {
+        Error::new(
+            ErrorKind::InvalidData,
+            format!("unable to find the {register}) invocation"),
+        )
+    };
+    let start = source.rfind(register).ok_or_else(invalid)? + register.len();
+    let end = start + source[start..].find(");").ok_or_else(invalid)?;
+
+    let mut modules: Vec<_> = source[start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|module| !module.is_empty())
+        .collect();
+    if modules.contains(&module) {
+        return Err(Error::new(
+            ErrorKind::AlreadyExists,
+            format!("{module} is already registered"),
+        ));
+    }
+    modules.push(module);
+    modules.sort_unstable();
+
+    // Format the list the same way rustfmt does: on the line of the macro if it fits, wrapped otherwise
+    let line_start = source[..start].rfind('\n').map_or(0, |index| index + 1);
+    let single_line = modules.join(", ");
+    if start - line_start + single_line.len() + 2 <= 120 {
+        return Ok(format!("{}{}{}", &source[..start], single_line, &source[end..]));
+    }
+
+    let mut list = String::from("\n   ");
+    let mut width = 3;
+    for module in modules {
+        if width + module.len() + 2 > 120 {
+            list.push_str("\n   ");
+            width = 3;
+        }
+        list.push(' ');
+        list.push_str(module);
+        list.push(',');
+        width += module.len() + 2;
+    }
+    list.push('\n');
+
+    Ok(format!("{}{}{}", &source[..start], list, &source[end..]))
+}
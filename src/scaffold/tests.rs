@@ -0,0 +1,154 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::process;
+
+use crate::scaffold::{declare_module, new_day, register_day, register_module};
+
+const LIB: &str = "macro_rules! register_years {}
+
+register_years!(year2023);
+
+pub mod answers;
+#[allow(dead_code)]
+mod dayxx;
+pub mod year2023;
+";
+
+const YEAR: &str = "pub mod day01;
+pub mod day03;
+
+register_days!(day01, day03);
+";
+
+#[test]
+fn test_register_module() {
+    assert_eq!(
+        register_module(YEAR, "register_days!(", "day02").unwrap(),
+        "pub mod day01;\npub mod day03;\n\nregister_days!(day01, day02, day03);\n"
+    );
+    assert_eq!(
+        register_module("register_days!();\n", "register_days!(", "day02").unwrap(),
+        "register_days!(day02);\n"
+    );
+
+    // A long list is wrapped the same way as rustfmt does
+    let days: Vec<_> = (1..=24).map(|day| format!("day{day:0>2}")).collect();
+    let source = format!("register_days!({});\n", days.join(", "));
+    assert_eq!(
+        register_module(&source, "register_days!(", "day25").unwrap(),
+        format!(
+            "register_days!(\n    {},\n    {},\n);\n",
+            days[..16].join(", "),
+            days[16..]
+                .iter()
+                .chain([&"day25".to_string()])
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    );
+
+    let error = register_module(YEAR, "register_days!(", "day03").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::AlreadyExists);
+    let error = register_module("pub mod day01;\n", "register_days!(", "day02").unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+}
+
+#[test]
+fn test_declare_module() {
+    assert_eq!(
+        declare_module(YEAR, "day02"),
+        "pub mod day01;\npub mod day02;\npub mod day03;\n\nregister_days!(day01, day03);\n"
+    );
+    assert_eq!(
+        declare_module(YEAR, "day04"),
+        "pub mod day01;\npub mod day03;\npub mod day04;\n\nregister_days!(day01, day03);\n"
+    );
+    assert_eq!(
+        declare_module("register_days!();\n", "day02"),
+        "pub mod day02;\n\nregister_days!();\n"
+    );
+
+    // A new year is declared after the existing modules
+    let lib = declare_module(
+        &register_module(LIB, "register_years!(", "year2024").unwrap(),
+        "year2024",
+    );
+    assert_eq!(
+        lib,
+        LIB.replace("register_years!(year2023)", "register_years!(year2023, year2024)")
+            .replace("pub mod year2023;\n", "pub mod year2023;\npub mod year2024;\n")
+    );
+    assert_eq!(
+        declare_module("pub mod day01;\n#[cfg(test)]\npub mod day03;\n", "day02"),
+        "pub mod day01;\npub mod day02;\n#[cfg(test)]\npub mod day03;\n"
+    );
+}
+
+#[test]
+fn test_register_day() {
+    assert_eq!(
+        register_day(
+            "pub const REGISTRATION: Registration = Registration::new::<Day07>(2023, 0, \"Title\", [true, true]);\n",
+            2024,
+            7
+        )
+        .unwrap(),
+        "pub const REGISTRATION: Registration = Registration::new::<Day07>(2024, 7, \"Title\", [true, true]);\n"
+    );
+    assert_eq!(
+        register_day("pub struct Day07;\n", 2024, 7).unwrap_err().kind(),
+        ErrorKind::InvalidData
+    );
+}
+
+#[test]
+fn test_new_day() {
+    let root = std::env::temp_dir().join(format!("advent_scaffold_{}", process::id()));
+    let path = |path: &str| -> PathBuf { root.join(path) };
+    fs::create_dir_all(path("src")).unwrap();
+    fs::write(path("src/lib.rs"), LIB).unwrap();
+
+    // The first day of a year creates and declares the year module
+    new_day(&root, 2024, 7).unwrap();
+    assert!(fs::read_to_string(path("src/lib.rs"))
+        .unwrap()
+        .contains("register_years!(year2023, year2024);"));
+    assert_eq!(
+        fs::read_to_string(path("src/year2024/mod.rs")).unwrap(),
+        "pub mod day07;\n\nregister_days!(day07);\n"
+    );
+    let module = fs::read_to_string(path("src/year2024/day07/mod.rs")).unwrap();
+    assert!(module.contains("use crate::year2024::day07::logic::{solve_part_one, solve_part_two};"));
+    assert!(module.contains("Registration::new::<Day07>(2024, 7, \"Title\", [true, true])"));
+    let tests = fs::read_to_string(path("src/year2024/day07/tests.rs")).unwrap();
+    assert!(tests.contains("use crate::year2024::day07::{Day07, REGISTRATION};"));
+    assert!(tests.contains("test_examples_common(&REGISTRATION);"));
+    assert!(path("inputs/2024/day07").exists());
+    assert_eq!(
+        fs::read_to_string(path("input_examples/2024/manifest.txt")).unwrap(),
+        "7 1 day07 ?\n7 2 day07 ?\n"
+    );
+
+    // A day that already exists is left untouched
+    fs::write(path("src/year2024/day07/logic.rs"), "solved").unwrap();
+    assert_eq!(new_day(&root, 2024, 7).unwrap_err().kind(), ErrorKind::AlreadyExists);
+    assert_eq!(
+        fs::read_to_string(path("src/year2024/day07/logic.rs")).unwrap(),
+        "solved"
+    );
+
+    new_day(&root, 2024, 3).unwrap();
+    assert_eq!(
+        fs::read_to_string(path("src/year2024/mod.rs")).unwrap(),
+        "pub mod day03;\npub mod day07;\n\nregister_days!(day03, day07);\n"
+    );
+    assert_eq!(
+        fs::read_to_string(path("input_examples/2024/manifest.txt")).unwrap(),
+        "7 1 day07 ?\n7 2 day07 ?\n3 1 day03 ?\n3 2 day03 ?\n"
+    );
+    assert_eq!(new_day(&root, 2024, 26).unwrap_err().kind(), ErrorKind::InvalidInput);
+
+    fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,132 @@
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::examples::Example;
+use crate::parameters::ParameterAssignment;
+use crate::registry::Registration;
+use crate::runner::{run_example, run_file, Part, RunReport};
+
+#[cfg(test)]
+mod tests;
+
+/// Modification time and size of a file, `None` if the file cannot be read
+type FileState = Option<(SystemTime, u64)>;
+
+fn file_state(path: &PathBuf) -> FileState {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// Detect the modifications of a set of files by polling their metadata
+#[derive(Debug, Clone)]
+pub struct FileWatcher {
+    files: Vec<(PathBuf, FileState)>,
+}
+
+impl FileWatcher {
+    /// Watch the files, their current state is the reference of the first poll
+    pub fn new(paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        Self {
+            files: paths
+                .into_iter()
+                .map(|path| {
+                    let state = file_state(&path);
+                    (path, state)
+                })
+                .collect(),
+        }
+    }
+
+    /// Indices of the files modified, created or removed since the previous poll
+    pub fn poll(&mut self) -> Vec<usize> {
+        self.files
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(index, (path, state))| {
+                let new_state = file_state(path);
+                (new_state != *state).then(|| {
+                    *state = new_state;
+                    index
+                })
+            })
+            .collect()
+    }
+}
+
+/// Describe the differences of the answers and times between two runs on the same input, one line per difference
+pub fn diff_reports(previous: &RunReport, current: &RunReport) -> Vec<String> {
+    let mut differences = vec![];
+    for (part, previous_solution, current_solution) in [
+        (1, &previous.part_01_sol, &current.part_01_sol),
+        (2, &previous.part_02_sol, &current.part_02_sol),
+    ] {
+        if previous_solution != current_solution {
+            differences.push(format!("part {part}: {previous_solution} -> {current_solution}"));
+        }
+    }
+
+    let delta = current.total_time as i128 - previous.total_time as i128;
+    differences.push(format!(
+        "total time: {} us -> {} us ({delta:+} us)",
+        previous.total_time, current.total_time
+    ));
+
+    differences
+}
+
+/// Run a day on the input file at `path` and on the examples, then run it again on the files modified since the
+/// previous run, checking them every `interval`, until interrupted. Every report is printed on the standard output
+/// along with its differences with the previous run on the same file. The panics of the solutions are reported in
+/// the results, the caller may hide them with a panic hook.
+pub fn watch_day(
+    registration: &Registration,
+    path: String,
+    examples: Vec<Example>,
+    part: Part,
+    parameters: &[ParameterAssignment],
+    timeout: Option<Duration>,
+    interval: Duration,
+) -> ! {
+    // Every watched file with the example it is the input of, `None` for the input of the day
+    let mut runs: Vec<(String, Option<Example>)> = vec![(path, None)];
+    runs.extend(examples.into_iter().map(|example| (example.path(), Some(example))));
+
+    let mut watcher = FileWatcher::new(runs.iter().map(|(path, _)| PathBuf::from(path)));
+    let mut previous_reports: Vec<Option<RunReport>> = vec![None; runs.len()];
+    let mut changed: Vec<usize> = (0..runs.len()).collect();
+    loop {
+        for index in changed {
+            let (path, example) = &runs[index];
+            let result = match example {
+                Some(example) => run_example(registration, example, part, parameters, timeout),
+                None => run_file(registration, part, parameters, timeout, path),
+            };
+            let report = match result {
+                Ok(report) => report,
+                Err(error) => {
+                    eprintln!("{error}");
+                    continue;
+                }
+            };
+
+            println!("==> {}", report.input);
+            println!("{report}");
+            for (part, expected) in [(1, &report.part_01_expected), (2, &report.part_02_expected)] {
+                if report.matches_expected(part) == Some(false) {
+                    println!("    part {part}: expected {}", expected.as_deref().unwrap_or_default());
+                }
+            }
+            if let Some(previous_report) = &previous_reports[index] {
+                for difference in diff_reports(previous_report, &report) {
+                    println!("    {difference}");
+                }
+            }
+            previous_reports[index] = Some(report);
+        }
+
+        thread::sleep(interval);
+        changed = watcher.poll();
+    }
+}
@@ -0,0 +1,75 @@
+use std::fs;
+use std::process;
+
+use crate::models::Answer;
+use crate::runner::{PartSolution, RunReport};
+use crate::watch::{diff_reports, FileWatcher};
+
+fn report(part_01_sol: PartSolution, part_02_sol: PartSolution, total_time: u128) -> RunReport {
+    RunReport {
+        year: 2023,
+        day: 7,
+        input: "input_examples/2023/day07".to_string(),
+        part_01_sol,
+        part_02_sol,
+        part_01_expected: None,
+        part_02_expected: None,
+        parse_time: 0,
+        prep_time: 0,
+        part_01_time: 0,
+        part_02_time: 0,
+        total_time,
+        parse_memory: None,
+        prep_memory: None,
+        part_01_memory: None,
+        part_02_memory: None,
+    }
+}
+
+#[test]
+fn test_file_watcher() {
+    let root = std::env::temp_dir().join(format!("advent_watch_{}", process::id()));
+    fs::create_dir_all(&root).unwrap();
+    let (input, example) = (root.join("input"), root.join("example"));
+    fs::write(&input, "1").unwrap();
+    let _ = fs::remove_file(&example);
+
+    let mut watcher = FileWatcher::new([input.clone(), example.clone()]);
+    assert_eq!(watcher.poll(), Vec::<usize>::new());
+
+    // The size changes, so the modification is seen even if the modification time has a coarse resolution
+    fs::write(&input, "12").unwrap();
+    assert_eq!(watcher.poll(), vec![0]);
+    assert_eq!(watcher.poll(), Vec::<usize>::new());
+
+    // Creating and removing a file are modifications as well
+    fs::write(&example, "3").unwrap();
+    fs::remove_file(&input).unwrap();
+    assert_eq!(watcher.poll(), vec![0, 1]);
+    assert_eq!(watcher.poll(), Vec::<usize>::new());
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn test_diff_reports() {
+    let solved = |answer: i128| PartSolution::Solved(Answer::Integer(answer));
+    let previous = report(solved(6440), solved(5905), 120);
+
+    // The time is always compared
+    assert_eq!(
+        diff_reports(&previous, &report(solved(6440), solved(5905), 100)),
+        vec!["total time: 120 us -> 100 us (-20 us)"]
+    );
+    assert_eq!(
+        diff_reports(
+            &previous,
+            &report(solved(6441), PartSolution::Panicked("overflow".to_string()), 150)
+        ),
+        vec![
+            "part 1: 6440 -> 6441",
+            "part 2: 5905 -> panicked: overflow",
+            "total time: 120 us -> 150 us (+30 us)",
+        ]
+    );
+}
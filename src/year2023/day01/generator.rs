@@ -0,0 +1,24 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+static WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+/// Generate `size` lines of letters, digits and spelled out digits, every line has at least one digit
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let mut line = String::new();
+            let digit_position = rng.gen_range(0..8);
+            for position in 0..8 {
+                if position == digit_position || rng.gen_bool(0.2) {
+                    line.push(char::from(b'1' + rng.gen_range(0..9)));
+                } else if rng.gen_bool(0.2) {
+                    line.push_str(WORDS.choose(rng).unwrap());
+                } else {
+                    line.push(char::from(b'a' + rng.gen_range(0..26)));
+                }
+            }
+            line + "\n"
+        })
+        .collect()
+}
@@ -0,0 +1,39 @@
+mod generator;
+mod logic;
+
+#[cfg(test)]
+mod tests;
+
+use rand::rngs::StdRng;
+
+use crate::models::{AdventSolution, Answer};
+use crate::parser::ParseError;
+use crate::registry::Registration;
+use crate::year2023::day01::generator::generate_input;
+use crate::year2023::day01::logic::{solve_part_one, solve_part_two};
+
+pub const REGISTRATION: Registration = Registration::new::<Day01>(2023, 1, "Trebuchet?!", [true, true]);
+
+#[derive(Default)]
+pub struct Day01 {
+    parsed_data: Option<String>,
+}
+
+impl AdventSolution for Day01 {
+    fn parse(&mut self, _data: String) -> Result<(), ParseError> {
+        self.parsed_data = Some(_data);
+        Ok(())
+    }
+
+    fn solve_part_one(&self) -> Answer {
+        solve_part_one(self.parsed_data.as_ref().unwrap()).into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        solve_part_two(self.parsed_data.as_ref().unwrap()).into()
+    }
+
+    fn generate_input(&self, size: usize, rng: &mut StdRng) -> Option<String> {
+        Some(generate_input(size, rng))
+    }
+}
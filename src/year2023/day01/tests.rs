@@ -0,0 +1,12 @@
+use crate::common::{test_examples_common, test_generated_input_common};
+use crate::year2023::day01::{Day01, REGISTRATION};
+
+#[test]
+fn test_examples() {
+    test_examples_common(&REGISTRATION);
+}
+
+#[test]
+fn test_generated_input() {
+    test_generated_input_common::<Day01>();
+}
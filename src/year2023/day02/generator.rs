@@ -0,0 +1,24 @@
+use itertools::Itertools;
+use rand::Rng;
+
+/// Generate `size` games of up to 6 subsets
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    (1..=size.max(1))
+        .map(|index| {
+            let subsets = (0..rng.gen_range(1..=6))
+                .map(|_| {
+                    let colors: Vec<_> = ["red", "green", "blue"]
+                        .into_iter()
+                        .filter(|_| rng.gen_bool(0.7))
+                        .collect();
+                    let colors = if colors.is_empty() { vec!["red"] } else { colors };
+                    colors
+                        .into_iter()
+                        .map(|color| format!("{} {color}", rng.gen_range(1..=20)))
+                        .join(", ")
+                })
+                .join("; ");
+            format!("Game {index}: {subsets}\n")
+        })
+        .collect()
+}
@@ -1,11 +1,11 @@
-use crate::day02::models::Game;
+use crate::year2023::day02::models::{Game, GameSubset};
 
-pub fn solve_part_one(data: &[Game]) -> u32 {
+pub fn solve_part_one(data: &[Game], bag: &GameSubset) -> u32 {
     data.iter()
         .filter(|game| {
             game.subsets
                 .iter()
-                .all(|subset| subset.red <= 12 && subset.green <= 13 && subset.blue <= 14)
+                .all(|subset| subset.red <= bag.red && subset.green <= bag.green && subset.blue <= bag.blue)
         })
         .map(|game| game.index)
         .sum()
@@ -0,0 +1,95 @@
+mod generator;
+mod logic;
+mod models;
+mod parser;
+
+#[cfg(test)]
+mod tests;
+
+use rand::rngs::StdRng;
+
+use crate::models::{AdventSolution, Answer};
+use crate::parameters::{Parameter, ParameterError, ParameterValue};
+use crate::parser::ParseError;
+use crate::registry::Registration;
+use crate::year2023::day02::generator::generate_input;
+use crate::year2023::day02::logic::{solve_part_one, solve_part_two};
+use crate::year2023::day02::models::{Game, GameSubset};
+use crate::year2023::day02::parser::parse_input;
+
+pub const REGISTRATION: Registration = Registration::new::<Day02>(2023, 2, "Cube Conundrum", [true, true]);
+
+pub struct Day02 {
+    /// Content of the bag for part one
+    bag: GameSubset,
+    parsed_data: Option<Vec<Game>>,
+}
+
+impl Default for Day02 {
+    fn default() -> Self {
+        Self {
+            bag: GameSubset {
+                red: 12,
+                green: 13,
+                blue: 14,
+            },
+            parsed_data: None,
+        }
+    }
+}
+
+impl AdventSolution for Day02 {
+    fn parse(&mut self, _data: String) -> Result<(), ParseError> {
+        self.parsed_data = Some(parse_input(_data)?);
+        Ok(())
+    }
+
+    fn solve_part_one(&self) -> Answer {
+        solve_part_one(self.parsed_data.as_ref().unwrap(), &self.bag).into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        solve_part_two(self.parsed_data.as_ref().unwrap()).into()
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        let default = Self::default().bag;
+        vec![
+            Parameter::new(
+                "red",
+                "Red cubes in the bag",
+                ParameterValue::Unsigned(default.red as usize),
+            ),
+            Parameter::new(
+                "green",
+                "Green cubes in the bag",
+                ParameterValue::Unsigned(default.green as usize),
+            ),
+            Parameter::new(
+                "blue",
+                "Blue cubes in the bag",
+                ParameterValue::Unsigned(default.blue as usize),
+            ),
+        ]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: ParameterValue) -> Result<(), ParameterError> {
+        // A bag holding more cubes than u32::MAX cannot make any game impossible
+        let count = match value {
+            ParameterValue::Unsigned(count) => count.try_into().unwrap_or(u32::MAX),
+            _ => unreachable!(),
+        };
+        match name {
+            "red" => self.bag.red = count,
+            "green" => self.bag.green = count,
+            "blue" => self.bag.blue = count,
+            _ => unreachable!(),
+        }
+
+        Ok(())
+    }
+
+    fn generate_input(&self, size: usize, rng: &mut StdRng) -> Option<String> {
+        Some(generate_input(size, rng))
+    }
+}
@@ -9,7 +9,8 @@ use nom::multi::{many1, separated_list1};
 use nom::sequence::tuple;
 use nom::{IResult, Parser};
 
-use crate::day02::models::{Game, GameSubset};
+use crate::parser::{finish, ParseError};
+use crate::year2023::day02::models::{Game, GameSubset};
 
 fn parse_cube(input: &str) -> IResult<&str, GameSubset> {
     map_res(
@@ -48,11 +49,6 @@ fn parse_game(input: &str) -> IResult<&str, Game> {
     .parse(input)
 }
 
-pub fn parse_input(input: String) -> Vec<Game> {
-    let (res, games) = many1(parse_game).parse(&input).unwrap();
-    if !res.is_empty() {
-        panic!("Unable to fully parse the input: {}", res);
-    }
-
-    games
+pub fn parse_input(input: String) -> Result<Vec<Game>, ParseError> {
+    finish(2, &input, many1(parse_game).parse(&input))
 }
@@ -0,0 +1,35 @@
+use crate::common::{test_examples_common, test_generated_input_common};
+use crate::year2023::day02::models::{Game, GameSubset};
+use crate::year2023::day02::parser::parse_input;
+use crate::year2023::day02::{Day02, REGISTRATION};
+
+impl GameSubset {
+    pub fn new(red: u32, green: u32, blue: u32) -> Self {
+        Self { red, green, blue }
+    }
+}
+
+#[test]
+fn test_parse_data() {
+    assert_eq!(
+        parse_input("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green".to_string()).unwrap(),
+        vec![Game {
+            index: 1,
+            subsets: vec![
+                GameSubset::new(4, 0, 3),
+                GameSubset::new(1, 2, 6),
+                GameSubset::new(0, 2, 0)
+            ],
+        }]
+    );
+}
+
+#[test]
+fn test_examples() {
+    test_examples_common(&REGISTRATION);
+}
+
+#[test]
+fn test_generated_input() {
+    test_generated_input_common::<Day02>();
+}
@@ -0,0 +1,33 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+static SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '=', '%', '&', '-'];
+
+/// Generate a `size` x `size` schematic of numbers and symbols
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    let size = size.max(1);
+    let mut grid = vec![vec!['.'; size]; size];
+
+    for line in grid.iter_mut() {
+        let mut column = 0;
+        while column < size {
+            if rng.gen_bool(0.1) {
+                line[column] = *SYMBOLS.choose(rng).unwrap();
+                column += 2;
+            } else if rng.gen_bool(0.2) {
+                // Numbers are followed by a '.' or a symbol, never by another number
+                let number = rng.gen_range(1..1000).to_string();
+                for (offset, digit) in number.chars().enumerate().take(size - column) {
+                    line[column + offset] = digit;
+                }
+                column += number.len() + 1;
+            } else {
+                column += 1;
+            }
+        }
+    }
+
+    grid.into_iter()
+        .map(|line| line.into_iter().collect::<String>() + "\n")
+        .collect()
+}
@@ -0,0 +1,38 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::year2023::day03::models::{EnginePart, Schematic, SchematicCell};
+
+/// Positions of the cells around an engine part, including its diagonals
+fn adjacent_cells<'a>(data: &'a Schematic, engine_part: &'a EnginePart) -> impl Iterator<Item = (usize, usize)> + 'a {
+    (engine_part.col_start..=engine_part.col_end)
+        .flat_map(|i_col| data.grid.neighbors8((engine_part.line, i_col)))
+        .filter(|position| !data.grid[*position].is_part())
+}
+
+pub fn solve_part_one(data: &Schematic) -> u32 {
+    data.engine_parts
+        .iter()
+        .filter(|engine_part| adjacent_cells(data, engine_part).any(|position| data.grid[position].is_symbol()))
+        .map(|engine_part| engine_part.value)
+        .sum()
+}
+
+pub fn solve_part_two(data: &Schematic) -> u32 {
+    let mut gear_connexions: HashMap<(usize, usize), Vec<u32>> = HashMap::new();
+
+    for engine_part in &data.engine_parts {
+        // A gear touching several digits of the part is only connected once
+        let gears: HashSet<_> = adjacent_cells(data, engine_part)
+            .filter(|position| data.grid[*position] == SchematicCell::Symbol('*'))
+            .collect();
+        for gear in gears {
+            gear_connexions.entry(gear).or_default().push(engine_part.value)
+        }
+    }
+
+    gear_connexions
+        .values()
+        .filter(|values| values.len() == 2)
+        .map(|values| values[0] * values[1])
+        .sum()
+}
@@ -1,5 +1,7 @@
 use std::fmt::{Display, Formatter};
 
+use crate::grid::Grid;
+
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub enum SchematicCell {
     Empty,
@@ -47,21 +49,14 @@ impl Display for EnginePart {
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct Schematic {
-    pub grid: Vec<Vec<SchematicCell>>,
-    pub width: usize,
-    pub height: usize,
+    pub grid: Grid<SchematicCell>,
     pub engine_parts: Vec<EnginePart>,
 }
 
 impl Display for Schematic {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "Grid:")?;
-        for line in &self.grid {
-            for cell in line {
-                write!(f, "{}", cell)?;
-            }
-            writeln!(f)?;
-        }
+        write!(f, "{}", self.grid)?;
 
         writeln!(f, "Engine parts:")?;
         for part in &self.engine_parts {
@@ -1,41 +1,29 @@
 use itertools::Itertools;
 
-use crate::day03::models::{EnginePart, Schematic, SchematicCell};
+use crate::grid::Grid;
+use crate::parser::{check_rectangular, ParseError};
+use crate::year2023::day03::models::{EnginePart, Schematic, SchematicCell};
 
 static ZERO_VALUE: u8 = b'0';
 
-pub fn parse_input(input: String) -> Schematic {
-    let grid: Vec<Vec<_>> = input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|cell| match cell {
-                    '.' => SchematicCell::Empty,
-                    '0'..='9' => SchematicCell::Part(cell as u8 - ZERO_VALUE),
-                    _ => SchematicCell::Symbol(cell),
-                })
-                .collect()
-        })
-        .collect();
+impl From<char> for SchematicCell {
+    fn from(value: char) -> Self {
+        match value {
+            '.' => Self::Empty,
+            '0'..='9' => Self::Part(value as u8 - ZERO_VALUE),
+            _ => Self::Symbol(value),
+        }
+    }
+}
 
-    // Compute grid dimension
-    let width = grid[0].len();
-    let height = grid.len();
+pub fn parse_input(input: String) -> Result<Schematic, ParseError> {
+    check_rectangular(3, &input)?;
 
-    // Add edges around the grid
-    let grid: Vec<Vec<_>> = std::iter::once(vec![SchematicCell::Empty; width + 2])
-        .chain(grid.into_iter().map(|line| {
-            std::iter::once(SchematicCell::Empty)
-                .chain(line)
-                .chain(std::iter::once(SchematicCell::Empty))
-                .collect()
-        }))
-        .chain(std::iter::once(vec![SchematicCell::Empty; width + 2]))
-        .collect();
+    let grid: Grid<SchematicCell> = Grid::parse(&input);
 
     // Extract engine part
     let engine_parts: Vec<_> = grid
-        .iter()
+        .lines()
         .enumerate()
         .flat_map(|(i_line, line)| {
             line.iter()
@@ -64,10 +52,5 @@ pub fn parse_input(input: String) -> Schematic {
         })
         .collect();
 
-    Schematic {
-        grid,
-        width: width + 2,
-        height: height + 2,
-        engine_parts,
-    }
+    Ok(Schematic { grid, engine_parts })
 }
@@ -0,0 +1,28 @@
+use crate::common::{test_examples_common, test_generated_input_common};
+use crate::parser::ParseError;
+use crate::year2023::day03::parser::parse_input;
+use crate::year2023::day03::{Day03, REGISTRATION};
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        parse_input("467..114..\n...*......\n..35..633\n".to_string()).err(),
+        Some(ParseError {
+            day: 3,
+            line: 3,
+            column: 1,
+            expected: "a line of 10 characters".to_string(),
+            snippet: "..35..633".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_examples() {
+    test_examples_common(&REGISTRATION);
+}
+
+#[test]
+fn test_generated_input() {
+    test_generated_input_common::<Day03>();
+}
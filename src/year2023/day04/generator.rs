@@ -0,0 +1,19 @@
+use itertools::Itertools;
+use rand::seq::index::sample;
+use rand::Rng;
+
+/// Generate `size` cards of 10 winning numbers and 25 numbers
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    let size = size.max(1);
+    let width = size.to_string().len();
+    let mut numbers = |count| {
+        sample(rng, 99, count)
+            .into_iter()
+            .map(|number| format!("{:>2}", number + 1))
+            .join(" ")
+    };
+
+    (1..=size)
+        .map(|index| format!("Card {index:>width$}: {} | {}\n", numbers(10), numbers(25)))
+        .collect()
+}
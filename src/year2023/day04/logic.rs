@@ -1,4 +1,4 @@
-use crate::day04::models::Game;
+use crate::year2023::day04::models::Game;
 
 pub fn solve_part_one(data: &[Game]) -> u32 {
     data.iter()
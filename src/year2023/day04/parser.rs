@@ -9,7 +9,8 @@ use nom::sequence::tuple;
 use nom::IResult;
 use nom::Parser;
 
-use crate::day04::models::Game;
+use crate::parser::{finish, ParseError};
+use crate::year2023::day04::models::Game;
 
 fn parse_game(input: &str) -> IResult<&str, Game> {
     map_res(
@@ -39,11 +40,6 @@ fn parse_game(input: &str) -> IResult<&str, Game> {
     .parse(input)
 }
 
-pub fn parse_input(input: String) -> Vec<Game> {
-    let (res, games) = many1(parse_game).parse(&input).unwrap();
-    if !res.is_empty() {
-        panic!("Unable to fully parse the input: {}", res);
-    }
-
-    games
+pub fn parse_input(input: String) -> Result<Vec<Game>, ParseError> {
+    finish(4, &input, many1(parse_game).parse(&input))
 }
@@ -0,0 +1,28 @@
+use std::collections::HashSet;
+
+use crate::common::{test_examples_common, test_generated_input_common};
+use crate::year2023::day04::models::Game;
+use crate::year2023::day04::parser::parse_input;
+use crate::year2023::day04::{Day04, REGISTRATION};
+
+#[test]
+fn test_parse_data() {
+    assert_eq!(
+        parse_input("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53".to_string()).unwrap(),
+        vec![Game {
+            index: 1,
+            winning: HashSet::from([41, 48, 83, 86, 17]),
+            draw: HashSet::from([83, 86, 6, 31, 17, 9, 48, 53]),
+        }]
+    );
+}
+
+#[test]
+fn test_examples() {
+    test_examples_common(&REGISTRATION);
+}
+
+#[test]
+fn test_generated_input() {
+    test_generated_input_common::<Day04>();
+}
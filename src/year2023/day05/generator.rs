@@ -0,0 +1,43 @@
+use itertools::Itertools;
+use rand::Rng;
+
+static CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+/// Generate an almanac with `size` seed ranges and maps of `size` disjoint mappings.
+/// Ranges get larger with `size`, up to the magnitude of the real inputs, so that small inputs can be bruteforced.
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    let size = size.max(1);
+    let scale = 10_u64.pow(size.min(8) as u32);
+    let seeds = (0..size)
+        .map(|_| format!("{} {}", rng.gen_range(0..10 * scale), rng.gen_range(1..scale)))
+        .join(" ");
+
+    let maps = CATEGORIES
+        .iter()
+        .tuple_windows()
+        .map(|(source, destination)| {
+            // Split the sources in consecutive disjoint ranges, possibly separated by gaps
+            let mut src_start = rng.gen_range(0..scale);
+            let mappings: String = (0..size)
+                .map(|_| {
+                    let mapping_size = rng.gen_range(1..2 * scale);
+                    let mapping = format!("{} {src_start} {mapping_size}\n", rng.gen_range(0..20 * scale));
+                    src_start += mapping_size + rng.gen_range(0..2) * rng.gen_range(1..scale / 2);
+                    mapping
+                })
+                .collect();
+            format!("{source}-to-{destination} map:\n{mappings}")
+        })
+        .join("\n");
+
+    format!("seeds: {seeds}\n\n{maps}")
+}
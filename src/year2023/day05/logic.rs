@@ -0,0 +1,112 @@
+use itertools::FoldWhile::{Continue, Done};
+use itertools::Itertools;
+
+use crate::assumptions::Assumption;
+use crate::interval::{Interval, IntervalSet};
+use crate::year2023::day05::models::{Almanac, Mapping};
+
+impl Mapping {
+    /// Values mapped by this mapping
+    fn source(&self) -> Interval {
+        Interval::with_len(self.src_start, self.size)
+    }
+}
+
+// The mappings being disjoint, every value is mapped at most once
+fn apply_map(values: &IntervalSet, mappings: &[Mapping]) -> IntervalSet {
+    let sources: IntervalSet = mappings.iter().map(Mapping::source).collect();
+
+    mappings
+        .iter()
+        .map(|mapping| {
+            values
+                .intersection(&mapping.source().into())
+                .shifted(mapping.dst_start - mapping.src_start)
+        })
+        .fold(values.difference(&sources), |acc, mapped| acc.union(&mapped))
+}
+
+fn map_values(values: IntervalSet, almanac: &Almanac) -> IntervalSet {
+    almanac
+        .mappings
+        .iter()
+        .fold(values, |acc, mappings| apply_map(&acc, mappings))
+}
+
+// We make the hypothesis that mappings form a disjoint set of mapping.
+fn map_value(value: i64, almanac: &Almanac) -> i64 {
+    almanac.mappings.iter().fold(value, |acc, mapping| {
+        mapping
+            .iter()
+            .fold_while(acc, |acc2, mapping| {
+                if acc2 >= mapping.src_start && acc2 < mapping.src_start + mapping.size {
+                    Done(acc2 + mapping.dst_start - mapping.src_start)
+                } else {
+                    Continue(acc2)
+                }
+            })
+            .into_inner()
+    })
+}
+
+/// Properties of the almanac that the solutions rely on
+pub fn check_assumptions(almanac: &Almanac) -> Vec<Assumption> {
+    // Mappings are sorted by source start, only consecutive mappings can overlap
+    let overlap = almanac.mappings.iter().enumerate().find_map(|(i_group, mappings)| {
+        mappings
+            .iter()
+            .tuple_windows()
+            .find(|(a, b)| b.src_start < a.src_start + a.size)
+            .map(|(a, b)| (i_group, a, b))
+    });
+
+    vec![
+        Assumption::check(
+            "disjoint_mappings",
+            "The source ranges of a map must not overlap",
+            overlap.is_none(),
+            || {
+                let (i_group, a, b) = overlap.unwrap();
+                format!("mappings {a} and {b} of map {} overlap", i_group + 1)
+            },
+        ),
+        Assumption::check(
+            "seed_pairs",
+            "Seeds must come in pairs of start and length for part two",
+            almanac.seeds.len() % 2 == 0,
+            || format!("there are {} seeds", almanac.seeds.len()),
+        ),
+    ]
+}
+
+pub fn solve_part_one(almanac: &Almanac) -> i64 {
+    almanac
+        .seeds
+        .iter()
+        .map(|seed| map_value(*seed, almanac))
+        .min()
+        .unwrap()
+}
+
+/// Reference implementation of part two mapping the seeds of the ranges one by one
+pub fn solve_part_two_bruteforce(almanac: &Almanac) -> i64 {
+    almanac
+        .seeds
+        .iter()
+        .tuples()
+        .flat_map(|(start, size)| *start..*start + *size)
+        .map(|seed| map_value(seed, almanac))
+        .min()
+        .unwrap()
+}
+
+pub fn solve_part_two(almanac: &Almanac) -> i64 {
+    let seeds = almanac
+        .seeds
+        .iter()
+        .tuples()
+        .map(|(start, size)| Interval::with_len(*start, *size))
+        .collect();
+
+    map_values(seeds, almanac).min().unwrap()
+}
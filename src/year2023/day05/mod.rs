@@ -0,0 +1,53 @@
+mod generator;
+mod logic;
+mod models;
+mod parser;
+
+#[cfg(test)]
+mod tests;
+
+use rand::rngs::StdRng;
+
+use crate::assumptions::Assumption;
+use crate::models::{AdventSolution, Answer};
+use crate::parser::ParseError;
+use crate::registry::Registration;
+use crate::year2023::day05::generator::generate_input;
+use crate::year2023::day05::logic::{check_assumptions, solve_part_one, solve_part_two, solve_part_two_bruteforce};
+use crate::year2023::day05::models::Almanac;
+use crate::year2023::day05::parser::parse_input;
+
+pub const REGISTRATION: Registration =
+    Registration::new::<Day05>(2023, 5, "If You Give A Seed A Fertilizer", [true, true]);
+
+#[derive(Default)]
+pub struct Day05 {
+    parsed_data: Option<Almanac>,
+}
+
+impl AdventSolution for Day05 {
+    fn parse(&mut self, _data: String) -> Result<(), ParseError> {
+        self.parsed_data = Some(parse_input(_data)?);
+        Ok(())
+    }
+
+    fn solve_part_one(&self) -> Answer {
+        solve_part_one(self.parsed_data.as_ref().unwrap()).into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        solve_part_two(self.parsed_data.as_ref().unwrap()).into()
+    }
+
+    fn solve_part_two_reference(&self) -> Option<Answer> {
+        Some(solve_part_two_bruteforce(self.parsed_data.as_ref().unwrap()).into())
+    }
+
+    fn check_assumptions(&self) -> Vec<Assumption> {
+        check_assumptions(self.parsed_data.as_ref().unwrap())
+    }
+
+    fn generate_input(&self, size: usize, rng: &mut StdRng) -> Option<String> {
+        Some(generate_input(size, rng))
+    }
+}
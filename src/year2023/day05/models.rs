@@ -7,12 +7,6 @@ pub struct Mapping {
     pub size: i64,
 }
 
-#[derive(Debug, Eq, PartialEq, Clone)]
-pub struct Range {
-    pub start: i64,
-    pub end: i64,
-}
-
 impl Display for Mapping {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
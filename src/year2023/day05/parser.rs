@@ -9,7 +9,8 @@ use nom::sequence::{delimited, tuple};
 use nom::IResult;
 use nom::Parser;
 
-use crate::day05::models::{Almanac, Mapping};
+use crate::parser::{finish, ParseError};
+use crate::year2023::day05::models::{Almanac, Mapping};
 
 fn parse_seeds(input: &str) -> IResult<&str, Vec<i64>> {
     map_res(
@@ -55,11 +56,6 @@ fn parse_almanac(input: &str) -> IResult<&str, Almanac> {
     .parse(input)
 }
 
-pub fn parse_input(input: String) -> Almanac {
-    let (res, almanac) = parse_almanac(&input).unwrap();
-    if !res.is_empty() {
-        panic!("Unable to fully parse the input: {}", res);
-    }
-
-    almanac
+pub fn parse_input(input: String) -> Result<Almanac, ParseError> {
+    finish(5, &input, parse_almanac(&input))
 }
@@ -1,42 +1,10 @@
-use crate::common::{test_part_one_common, test_part_two_common};
-use crate::day05::models::{Almanac, Mapping};
-use crate::day05::parser::parse_input;
-use crate::day05::Day05;
+use crate::common::{test_against_reference_common, test_examples_common, test_generated_input_common};
+use crate::parser::ParseError;
+use crate::year2023::day05::models::{Almanac, Mapping};
+use crate::year2023::day05::parser::parse_input;
+use crate::year2023::day05::{Day05, REGISTRATION};
 
-static INPUT_EXAMPLE: &str = "seeds: 79 14 55 13
-
-seed-to-soil map:
-50 98 2
-52 50 48
-
-soil-to-fertilizer map:
-0 15 37
-37 52 2
-39 0 15
-
-fertilizer-to-water map:
-49 53 8
-0 11 42
-42 0 7
-57 7 4
-
-water-to-light map:
-88 18 7
-18 25 70
-
-light-to-temperature map:
-45 77 23
-81 45 19
-68 64 13
-
-temperature-to-humidity map:
-0 69 1
-1 0 69
-
-humidity-to-location map:
-60 56 37
-56 93 4
-";
+static INPUT_EXAMPLE: &str = include_str!("../../../input_examples/2023/day05");
 
 impl Mapping {
     pub fn new(src_start: i64, dst_start: i64, size: i64) -> Self {
@@ -52,7 +20,7 @@ impl Mapping {
 fn test_parse_data() {
     // Mapping order is not the same because we sorted the mapping during the parsing
     assert_eq!(
-        parse_input(INPUT_EXAMPLE.to_string()),
+        parse_input(INPUT_EXAMPLE.to_string()).unwrap(),
         Almanac {
             seeds: vec![79, 14, 55, 13,],
             mappings: vec![
@@ -82,11 +50,30 @@ fn test_parse_data() {
 }
 
 #[test]
-fn test_part_one() {
-    test_part_one_common(Day05::default(), INPUT_EXAMPLE, 35);
+fn test_parse_error() {
+    assert_eq!(
+        parse_input("seeds: 79 14\n\nseed-to-soil map:\n50 98 x\n".to_string()),
+        Err(ParseError {
+            day: 5,
+            line: 4,
+            column: 7,
+            expected: "a number".to_string(),
+            snippet: "50 98 x".to_string(),
+        })
+    )
+}
+
+#[test]
+fn test_examples() {
+    test_examples_common(&REGISTRATION);
+}
+
+#[test]
+fn test_generated_input() {
+    test_generated_input_common::<Day05>();
 }
 
 #[test]
-fn test_part_two() {
-    test_part_two_common(Day05::default(), INPUT_EXAMPLE, 46);
+fn test_against_reference() {
+    test_against_reference_common::<Day05>(&[1, 2, 3], |_| {});
 }
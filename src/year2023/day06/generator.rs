@@ -0,0 +1,19 @@
+use itertools::Itertools;
+use rand::Rng;
+
+/// Generate `size` races, at most 4 so that the numbers of part two stay small enough
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    let races: Vec<_> = (0..size.clamp(1, 4))
+        .map(|_| {
+            let time: i64 = rng.gen_range(10..100);
+            // The record must be beatable
+            (time, rng.gen_range(1..time * time / 4))
+        })
+        .collect();
+
+    format!(
+        "Time:     {}\nDistance: {}\n",
+        races.iter().map(|(time, _)| format!("{time:>4}")).join(" "),
+        races.iter().map(|(_, distance)| format!("{distance:>4}")).join(" ")
+    )
+}
@@ -1,22 +1,14 @@
-use crate::day06::models::Races;
-
-// Check if value has an integer square root and returns it.
-fn perfect_square_root(value: i64) -> Option<i64> {
-    let res = ((value as f64).sqrt() + 0.5) as i64;
-    if res * res == value {
-        Some(res)
-    } else {
-        None
-    }
-}
+use crate::math::IntegerSqrt;
+use crate::year2023::day06::models::Races;
 
 // Compute the minimal value for which we would go further than the reference.
 // This correspond to the lower integer root of the inequality x(t-x)>=d
 fn compute_lower(time: i64, distance: i64) -> i64 {
     let delta = time * time - 4 * distance;
 
-    match perfect_square_root(delta) {
-        None => ((time as f64 - (delta as f64).sqrt()) / 2f64).ceil() as i64,
+    match delta.exact_sqrt() {
+        // ceil((t - sqrt(delta)) / 2), the square root being strictly between root and root + 1
+        None => (time - delta.floor_sqrt() + 1).div_euclid(2),
         Some(psr) => (time - psr) / 2 + 1,
     }
 }
@@ -26,16 +18,28 @@ fn compute_lower(time: i64, distance: i64) -> i64 {
 fn compute_higher(time: i64, distance: i64) -> i64 {
     let delta = time * time - 4 * distance;
 
-    match perfect_square_root(delta) {
-        None => ((time as f64 + (delta as f64).sqrt()) / 2f64).floor() as i64,
+    match delta.exact_sqrt() {
+        // floor((t + sqrt(delta)) / 2), the square root being strictly between root and root + 1
+        None => (time + delta.floor_sqrt()).div_euclid(2),
         Some(psr) => (time + psr - 1) / 2,
     }
 }
 
+// Compute the number of holding times going further than the reference
+fn count_ways(time: i64, distance: i64) -> i64 {
+    // The inequality has no solution when its discriminant is negative, the record cannot be beaten
+    if time * time < 4 * distance {
+        return 0;
+    }
+
+    // With a null discriminant, the only root reaches the reference without going further
+    (compute_higher(time, distance) - compute_lower(time, distance) + 1).max(0)
+}
+
 pub fn solve_part_one(data: &Races) -> i64 {
     data.races
         .iter()
-        .map(|race| compute_higher(race.time, race.distance) - compute_lower(race.time, race.distance) + 1)
+        .map(|race| count_ways(race.time, race.distance))
         .product()
 }
 
@@ -48,5 +52,5 @@ pub fn solve_part_two(data: &Races) -> i64 {
         acc * 10i64.pow(race.distance.ilog10() + 1) + race.distance
     });
 
-    compute_higher(time, distance) - compute_lower(time, distance) + 1
+    count_ways(time, distance)
 }
@@ -8,7 +8,8 @@ use nom::sequence::{delimited, tuple};
 use nom::IResult;
 use nom::Parser;
 
-use crate::day06::models::{Race, Races};
+use crate::parser::{finish, ParseError};
+use crate::year2023::day06::models::{Race, Races};
 
 fn parse_times(input: &str) -> IResult<&str, Vec<i64>> {
     map_res(
@@ -45,11 +46,6 @@ fn parse_races(input: &str) -> IResult<&str, Races> {
     .parse(input)
 }
 
-pub fn parse_input(input: String) -> Races {
-    let (res, almanac) = parse_races(&input).unwrap();
-    if !res.is_empty() {
-        panic!("Unable to fully parse the input: {}", res);
-    }
-
-    almanac
+pub fn parse_input(input: String) -> Result<Races, ParseError> {
+    finish(6, &input, parse_races(&input))
 }
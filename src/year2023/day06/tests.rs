@@ -0,0 +1,44 @@
+use crate::common::{test_examples_common, test_generated_input_common};
+use crate::models::{AdventSolution, Answer};
+use crate::year2023::day06::{Day06, REGISTRATION};
+
+#[test]
+fn test_examples() {
+    test_examples_common(&REGISTRATION);
+}
+
+#[test]
+fn test_generated_input() {
+    test_generated_input_common::<Day06>();
+}
+
+#[test]
+fn test_every_small_race() {
+    for time in 1..=40i64 {
+        // Including the records that cannot be beaten
+        for distance in 1..time * time / 4 + 5 {
+            let mut solution = Day06::default();
+            solution.parse(format!("Time: {time}\nDistance: {distance}\n")).unwrap();
+
+            let expected = (0..=time).filter(|hold| hold * (time - hold) > distance).count();
+            assert_eq!(
+                solution.solve_part_one(),
+                Answer::from(expected),
+                "time {time}, distance {distance}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_unbeatable_race() {
+    let mut solution = Day06::default();
+    solution.parse("Time: 1 2\nDistance: 5 9\n".to_string()).unwrap();
+    assert_eq!(solution.solve_part_one(), Answer::from(0));
+    assert_eq!(solution.solve_part_two(), Answer::from(0));
+
+    // Holding the button half of the time reaches the record exactly
+    let mut solution = Day06::default();
+    solution.parse("Time: 4\nDistance: 4\n".to_string()).unwrap();
+    assert_eq!(solution.solve_part_one(), Answer::from(0));
+}
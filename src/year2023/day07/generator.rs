@@ -0,0 +1,15 @@
+use rand::Rng;
+
+static CARDS: &[u8] = b"23456789TJQKA";
+
+/// Generate `size` hands with their bid
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let hand: String = (0..5)
+                .map(|_| char::from(CARDS[rng.gen_range(0..CARDS.len())]))
+                .collect();
+            format!("{hand} {}\n", rng.gen_range(1..1000))
+        })
+        .collect()
+}
@@ -0,0 +1,43 @@
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use nom::character::complete::{digit1, line_ending, one_of, space1};
+use nom::combinator::{map, map_res, opt};
+use nom::multi::{count, many1};
+use nom::sequence::tuple;
+use nom::{IResult, Parser};
+
+use crate::parser::{finish, ParseError};
+use crate::year2023::day07::models::Hand;
+
+static ZERO_VALUE: u8 = b'0';
+
+fn parse_card(input: &str) -> IResult<&str, u8> {
+    map(one_of("23456789TJQKA"), |char| match char {
+        '2'..='9' => char as u8 - ZERO_VALUE,
+        'T' => 10,
+        'J' => 11,
+        'Q' => 12,
+        'K' => 13,
+        'A' => 14,
+        _ => unreachable!(),
+    })
+    .parse(input)
+}
+
+pub fn parse_hand(input: &str) -> IResult<&str, Hand> {
+    map_res(
+        tuple((count(parse_card, 5), space1, digit1, opt(line_ending))),
+        |(cards, _, bid, _)| {
+            Ok::<_, ParseIntError>(Hand {
+                cards,
+                bid: usize::from_str(bid)?,
+            })
+        },
+    )
+    .parse(input)
+}
+
+pub fn parse_input(input: String) -> Result<Vec<Hand>, ParseError> {
+    finish(7, &input, many1(parse_hand).parse(&input))
+}
@@ -0,0 +1,55 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Create a unique node name from an index, it never ends with 'A' or 'Z'
+fn node_name(index: usize) -> String {
+    let mut name = String::new();
+    let mut index = index;
+    for _ in 0..3 {
+        name.insert(0, char::from(b'B' + (index % 24) as u8));
+        index /= 24;
+    }
+    format!("{}{name}", if index > 0 { index.to_string() } else { String::new() })
+}
+
+/// Generate a map with `size` directions where every ghost follows a cycle of about `size` nodes.
+/// A ghost starting at a node ending with 'A' reaches a node ending with 'Z' after as many steps
+/// as it then needs to reach it again, which is the shape of the real inputs.
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    let size = size.max(1);
+    let directions: String = (0..size).map(|_| *['L', 'R'].choose(rng).unwrap()).collect();
+
+    let mut nodes = vec![];
+    let mut index = 0;
+    let ghost_count = rng.gen_range(1..=4);
+    for ghost in 0..ghost_count {
+        let (start, end) = if ghost == 0 {
+            ("AAA".to_string(), "ZZZ".to_string())
+        } else {
+            (
+                format!("{}{ghost}A", char::from(b'A' + ghost as u8)),
+                format!("{}{ghost}Z", char::from(b'A' + ghost as u8)),
+            )
+        };
+
+        // Both directions lead to the next node of the cycle
+        let cycle: Vec<_> = (0..rng.gen_range(size..=2 * size))
+            .map(|_| {
+                index += 1;
+                node_name(index)
+            })
+            .collect();
+        nodes.push((start, cycle[0].clone()));
+        for (node, next) in cycle.iter().zip(cycle.iter().skip(1).chain([&end])) {
+            nodes.push((node.clone(), next.clone()));
+        }
+        nodes.push((end, cycle[0].clone()));
+    }
+    nodes.shuffle(rng);
+
+    let nodes: String = nodes
+        .into_iter()
+        .map(|(node, next)| format!("{node} = ({next}, {next})\n"))
+        .collect();
+    format!("{directions}\n\n{nodes}")
+}
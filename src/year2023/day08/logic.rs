@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use crate::geometry::Turn;
+use crate::math::merge_periodicities;
+use crate::year2023::day08::models::NavigationMap;
+
+/// Compute every moment where the ghost will be at en and position that is synchronised with
+/// the navigation map.
+/// Solution have the form vec((offset, periodicity)).
+/// Every combination will be found at steps offset + periodicity * n where n is a positive integer
+fn compute_periodicities(current: &usize, data: &NavigationMap) -> Vec<(i128, i128)> {
+    let mut current = *current;
+    let mut to_check: HashMap<_, _> = HashMap::new();
+    let mut current_pos = 0;
+    let mut current_step: i128 = 0;
+    for direction in data.instructions.iter().cycle() {
+        // Make progress
+        current = match direction {
+            Turn::Left => data.nodes[current].left,
+            Turn::Right => data.nodes[current].right,
+        };
+
+        // Update positions
+        current_pos = (current_pos + 1) % data.instructions.len();
+        current_step += 1;
+
+        if data.nodes[current].name.ends_with('Z') {
+            let new_check = (&data.nodes[current].name, current_pos);
+            if let Some(last_step) = to_check.get(&new_check) {
+                let periodicity = current_step - last_step;
+                return to_check
+                    .values()
+                    .filter(|step| *step >= last_step)
+                    .map(|step| (*step, periodicity))
+                    .collect();
+            }
+
+            to_check.insert(new_check, current_step);
+        }
+    }
+
+    unreachable!()
+}
+
+pub fn solve_part_one(data: &NavigationMap) -> u32 {
+    // Detect the end position
+    let end = data.nodes.iter().position(|node| node.name.as_str() == "ZZZ").unwrap();
+
+    // At the beginning, the current position is at the start
+    let mut current = data.nodes.iter().position(|node| node.name.as_str() == "AAA").unwrap();
+
+    // Iterate over the instruction until we reach the end
+    let mut steps = 0;
+    for direction in data.instructions.iter().cycle() {
+        current = match direction {
+            Turn::Left => data.nodes[current].left,
+            Turn::Right => data.nodes[current].right,
+        };
+
+        steps += 1;
+
+        if current == end {
+            break;
+        }
+    }
+
+    steps
+}
+
+pub fn solve_part_two(data: &NavigationMap) -> i128 {
+    // At the beginning, the current position is every nodes that ends with the letter a
+    let currents: Vec<_> = data
+        .nodes
+        .iter()
+        .enumerate()
+        .filter(|(_, node)| node.name.ends_with('A'))
+        .map(|(position, _)| position)
+        .collect();
+
+    // We need to search for a pattern
+    let periodicities: Vec<_> = currents
+        .iter()
+        .map(|current| compute_periodicities(current, data))
+        .collect();
+
+    periodicities
+        .iter()
+        .fold(vec![(0, 1)], |acc, periods| {
+            acc.into_iter()
+                .flat_map(|(acc_offset, acc_period)| {
+                    periods.iter().filter_map(move |(offset, period)| {
+                        merge_periodicities((acc_offset, acc_period), (*offset, *period))
+                    })
+                })
+                .collect()
+        })
+        .into_iter()
+        .map(|(offset, _)| offset)
+        .min()
+        .unwrap()
+}
@@ -1,8 +1,4 @@
-#[derive(Debug, PartialEq, Clone)]
-pub enum Direction {
-    Left,
-    Right,
-}
+use crate::geometry::Turn;
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Node {
@@ -13,6 +9,6 @@ pub struct Node {
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct NavigationMap {
-    pub instructions: Vec<Direction>,
+    pub instructions: Vec<Turn>,
     pub nodes: Vec<Node>,
 }
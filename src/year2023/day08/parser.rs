@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+
+use nom::bytes::complete::tag;
+use nom::character::complete::{alphanumeric1, line_ending, one_of};
+use nom::combinator::{map, opt};
+use nom::multi::many1;
+use nom::sequence::{terminated, tuple};
+use nom::IResult;
+use nom::Parser;
+
+use crate::geometry::Turn;
+use crate::parser::{finish, ParseError};
+use crate::year2023::day08::models::{NavigationMap, Node};
+
+fn parse_directions(input: &str) -> IResult<&str, Vec<Turn>> {
+    terminated(
+        many1(map(one_of("LR"), |char| Turn::from_letter(char).unwrap())),
+        many1(line_ending),
+    )
+    .parse(input)
+}
+
+fn parse_node(input: &str) -> IResult<&str, (&str, &str, &str)> {
+    map(
+        tuple((
+            alphanumeric1,
+            tag(" = ("),
+            alphanumeric1,
+            tag(", "),
+            alphanumeric1,
+            tag(")"),
+            opt(line_ending),
+        )),
+        |(name, _, left, _, right, _, _): (&str, _, &str, _, &str, _, _)| (name, left, right),
+    )
+    .parse(input)
+}
+
+fn build_nodes(input: &str, nodes_data: Vec<(&str, &str, &str)>) -> Result<Vec<Node>, ParseError> {
+    // First build a hashmap of node names in order to be able to get them quickly after
+    let nodes_positions: HashMap<_, _> = nodes_data
+        .iter()
+        .enumerate()
+        .map(|(position, (name, _, _))| (*name, position))
+        .collect();
+
+    // Then create the vec of nodes iteratively, every referenced node must be defined
+    let get_position = |name: &str| {
+        nodes_positions
+            .get(name)
+            .copied()
+            .ok_or_else(|| ParseError::at(8, input, name, "the name of a defined node"))
+    };
+    nodes_data
+        .into_iter()
+        .map(|(name, left, right)| {
+            Ok(Node {
+                name: name.to_string(),
+                left: get_position(left)?,
+                right: get_position(right)?,
+            })
+        })
+        .collect()
+}
+
+pub fn parse_input(input: String) -> Result<NavigationMap, ParseError> {
+    let (instructions, nodes_data) = finish(8, &input, tuple((parse_directions, many1(parse_node))).parse(&input))?;
+
+    Ok(NavigationMap {
+        instructions,
+        nodes: build_nodes(&input, nodes_data)?,
+    })
+}
@@ -0,0 +1,20 @@
+use itertools::Itertools;
+use rand::Rng;
+
+/// Generate `size` histories of 21 values following polynomials of degree at most 4
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let coefficients: Vec<i64> = (0..rng.gen_range(1..=5)).map(|_| rng.gen_range(-10..=10)).collect();
+            (0..21_i64)
+                .map(|x| {
+                    coefficients
+                        .iter()
+                        .rev()
+                        .fold(0, |value, coefficient| value * x + coefficient)
+                })
+                .join(" ")
+                + "\n"
+        })
+        .collect()
+}
@@ -1,6 +1,6 @@
 use itertools::Itertools;
 
-use crate::day09::models::SensorReport;
+use crate::year2023::day09::models::SensorReport;
 
 fn generate_next_line(line: &[i64]) -> Vec<i64> {
     line.iter()
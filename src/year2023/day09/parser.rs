@@ -0,0 +1,27 @@
+use std::str::FromStr;
+
+use nom::bytes::complete::tag;
+use nom::character::complete::{digit1, line_ending, space1};
+use nom::combinator::{map, map_res, opt, recognize};
+use nom::multi::{many1, separated_list1};
+use nom::sequence::{terminated, tuple};
+use nom::{IResult, Parser};
+
+use crate::parser::{finish, ParseError};
+use crate::year2023::day09::models::SensorReport;
+
+fn parse_number(input: &str) -> IResult<&str, i64> {
+    map_res(recognize(tuple((opt(tag("-")), digit1))), i64::from_str).parse(input)
+}
+
+fn parse_sensor_report(input: &str) -> IResult<&str, SensorReport> {
+    map(
+        many1(terminated(separated_list1(space1, parse_number), opt(line_ending))),
+        |values_history| SensorReport { values_history },
+    )
+    .parse(input)
+}
+
+pub fn parse_input(input: String) -> Result<SensorReport, ParseError> {
+    finish(9, &input, parse_sensor_report(&input))
+}
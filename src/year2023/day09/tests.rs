@@ -0,0 +1,12 @@
+use crate::common::{test_examples_common, test_generated_input_common};
+use crate::year2023::day09::{Day09, REGISTRATION};
+
+#[test]
+fn test_examples() {
+    test_examples_common(&REGISTRATION);
+}
+
+#[test]
+fn test_generated_input() {
+    test_generated_input_common::<Day09>();
+}
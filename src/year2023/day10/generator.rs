@@ -0,0 +1,42 @@
+use rand::Rng;
+
+static PIPES: &[u8] = b"|-LJ7F.";
+
+/// Generate a `size` x `size` field of random pipes crossed by a rectangular loop starting at its top left corner
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    let size = size.max(3);
+    let mut grid: Vec<Vec<char>> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| char::from(PIPES[rng.gen_range(0..PIPES.len())]))
+                .collect()
+        })
+        .collect();
+
+    let top = rng.gen_range(0..size - 2);
+    let bottom = rng.gen_range(top + 1..size);
+    let left = rng.gen_range(0..size - 2);
+    let right = rng.gen_range(left + 1..size);
+    grid[top][left + 1..right].fill('-');
+    grid[bottom][left + 1..right].fill('-');
+    for line in &mut grid[top + 1..bottom] {
+        line[left] = '|';
+        line[right] = '|';
+    }
+    grid[top][left] = 'S';
+    grid[top][right] = '7';
+    grid[bottom][left] = 'L';
+    grid[bottom][right] = 'J';
+
+    // The start must only connect to the loop
+    if top > 0 {
+        grid[top - 1][left] = '.';
+    }
+    if left > 0 {
+        grid[top][left - 1] = '.';
+    }
+
+    grid.into_iter()
+        .map(|line| line.into_iter().collect::<String>() + "\n")
+        .collect()
+}
@@ -0,0 +1,127 @@
+use std::collections::HashSet;
+
+use crate::geometry::{Direction, Point2};
+use crate::year2023::day10::models::{Maze, PipeLoop, Tile};
+
+impl Tile {
+    /// Return the output direction considering we entered the tile from the given direction
+    fn get_output_direction(&self, direction: Direction) -> Option<Direction> {
+        match (self, direction) {
+            (Tile::Vertical, direction) => Some(direction),
+            (Tile::Horizontal, direction) => Some(direction),
+            (Tile::TopLeft, Direction::Up) => Some(Direction::Right),
+            (Tile::TopLeft, Direction::Left) => Some(Direction::Down),
+            (Tile::TopRight, Direction::Up) => Some(Direction::Left),
+            (Tile::TopRight, Direction::Right) => Some(Direction::Down),
+            (Tile::BottomRight, Direction::Down) => Some(Direction::Left),
+            (Tile::BottomRight, Direction::Right) => Some(Direction::Up),
+            (Tile::BottomLeft, Direction::Down) => Some(Direction::Right),
+            (Tile::BottomLeft, Direction::Left) => Some(Direction::Up),
+            (_, _) => None,
+        }
+    }
+
+    /// Return true if the tile is pointing out to that direction.
+    fn is_pointing(&self, direction: Direction, start_tile: Tile) -> bool {
+        let current_tile = if self == &Tile::Start { start_tile } else { *self };
+
+        match current_tile {
+            Tile::Ground => false,
+            Tile::Vertical => direction == Direction::Up || direction == Direction::Down,
+            Tile::Horizontal => direction == Direction::Right || direction == Direction::Left,
+            Tile::TopLeft => direction == Direction::Down || direction == Direction::Right,
+            Tile::TopRight => direction == Direction::Down || direction == Direction::Left,
+            Tile::BottomLeft => direction == Direction::Up || direction == Direction::Right,
+            Tile::BottomRight => direction == Direction::Up || direction == Direction::Left,
+            Tile::Start => unreachable!(),
+        }
+    }
+}
+
+impl From<(Direction, Direction)> for Tile {
+    fn from(value: (Direction, Direction)) -> Self {
+        match value {
+            (Direction::Up, Direction::Up) => Self::Vertical,
+            (Direction::Down, Direction::Down) => Self::Vertical,
+            (Direction::Right, Direction::Right) => Self::Horizontal,
+            (Direction::Left, Direction::Left) => Self::Horizontal,
+            (Direction::Down, Direction::Left) => Self::TopLeft,
+            (Direction::Right, Direction::Up) => Self::TopLeft,
+            (Direction::Down, Direction::Right) => Self::TopRight,
+            (Direction::Left, Direction::Up) => Self::TopRight,
+            (Direction::Up, Direction::Right) => Self::BottomRight,
+            (Direction::Left, Direction::Down) => Self::BottomRight,
+            (Direction::Up, Direction::Left) => Self::BottomLeft,
+            (Direction::Right, Direction::Down) => Self::BottomLeft,
+            (_, _) => unreachable!(),
+        }
+    }
+}
+
+pub fn get_loop(maze: &Maze) -> PipeLoop {
+    // Determinate the loop direction by starting every starting direction
+    for starting_direction in [Direction::Right, Direction::Down, Direction::Left, Direction::Up] {
+        let mut size = 0;
+        let mut direction = starting_direction;
+        let mut position = maze.start;
+        let mut loop_elements = HashSet::new();
+        loop {
+            // Advance position depending on the direction, the loop cannot leave the maze
+            position = match maze.tiles.neighbor(position.position(), direction.offset()) {
+                None => break,
+                Some(neighbor) => Point2::from_position(neighbor),
+            };
+
+            // Add the tile in the loop elements
+            loop_elements.insert(position);
+
+            size += 1;
+
+            // If we reached the start again, success
+            if position == maze.start {
+                return PipeLoop {
+                    start_tile: Tile::from((starting_direction, direction)),
+                    loop_elements,
+                    size,
+                };
+            }
+
+            // Get the output direction
+            direction = match maze.tiles[position.position()].get_output_direction(direction) {
+                None => break,
+                Some(direction) => direction,
+            };
+        }
+    }
+
+    unreachable!()
+}
+
+pub fn solve_part_one(pipe_loop: &PipeLoop) -> usize {
+    pipe_loop.size / 2
+}
+
+pub fn solve_part_two(data: &Maze, pipe_loop: &PipeLoop) -> i64 {
+    let mut inner_count = 0;
+    for (i_line, line) in data.tiles.lines().enumerate() {
+        let mut pointing_north = 0;
+        let mut pointing_south = 0;
+
+        for (i_column, tile) in line.iter().enumerate() {
+            let position = Point2::new(i_column, i_line);
+
+            if pipe_loop.loop_elements.contains(&position) {
+                if tile.is_pointing(Direction::Up, pipe_loop.start_tile) {
+                    pointing_north += 1;
+                }
+                if tile.is_pointing(Direction::Down, pipe_loop.start_tile) {
+                    pointing_south += 1;
+                }
+            } else if pointing_north % 2 == 1 && pointing_south % 2 == 1 {
+                inner_count += 1;
+            }
+        }
+    }
+
+    inner_count
+}
@@ -0,0 +1,56 @@
+mod generator;
+mod logic;
+mod models;
+mod parser;
+
+#[cfg(test)]
+mod tests;
+
+use rand::rngs::StdRng;
+
+use crate::models::{AdventSolution, Answer};
+use crate::parser::ParseError;
+use crate::registry::Registration;
+use crate::year2023::day10::generator::generate_input;
+use crate::year2023::day10::logic::{get_loop, solve_part_one, solve_part_two};
+use crate::year2023::day10::models::{Maze, PipeLoop};
+use crate::year2023::day10::parser::parse_input;
+
+pub const REGISTRATION: Registration = Registration::new::<Day10>(2023, 10, "Pipe Maze", [true, true]);
+
+#[derive(Default)]
+pub struct Day10 {
+    parsed_data: Option<Maze>,
+    prepared_data: Option<PipeLoop>,
+}
+
+impl AdventSolution for Day10 {
+    fn parse(&mut self, _data: String) -> Result<(), ParseError> {
+        self.parsed_data = Some(parse_input(_data)?);
+        Ok(())
+    }
+
+    fn prepare(&mut self) {
+        self.prepared_data = Some(get_loop(self.parsed_data.as_ref().unwrap()));
+    }
+
+    fn solve_part_one(&self) -> Answer {
+        solve_part_one(self.prepared_data.as_ref().unwrap()).into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        solve_part_two(self.parsed_data.as_ref().unwrap(), self.prepared_data.as_ref().unwrap()).into()
+    }
+
+    fn generate_input(&self, size: usize, rng: &mut StdRng) -> Option<String> {
+        Some(generate_input(size, rng))
+    }
+
+    fn render(&self) -> Option<String> {
+        let maze = self.parsed_data.as_ref()?;
+        match &self.prepared_data {
+            Some(pipe_loop) => Some(maze.render_loop(pipe_loop)),
+            None => Some(maze.to_string()),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+
+use colored::Colorize;
+
+use crate::geometry::Point2;
+use crate::grid::Grid;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum Tile {
+    Ground,
+    Start,
+    Vertical,
+    Horizontal,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Tile::Ground => ".",
+                Tile::Start => "S",
+                Tile::Vertical => "│",
+                Tile::Horizontal => "─",
+                Tile::TopLeft => "┌",
+                Tile::TopRight => "┐",
+                Tile::BottomLeft => "└",
+                Tile::BottomRight => "┘",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Maze {
+    pub tiles: Grid<Tile>,
+    pub start: Point2<usize>,
+}
+
+impl Display for Maze {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Grid:")?;
+        write!(f, "{}", self.tiles)?;
+
+        writeln!(f, "Start: {}", self.start)
+    }
+}
+
+impl Maze {
+    /// Render the maze with the loop in green and its start in red
+    pub fn render_loop(&self, pipe_loop: &PipeLoop) -> String {
+        let mut rendered = self.tiles.render(|position, tile| {
+            let position = Point2::from_position(position);
+            if position == self.start {
+                pipe_loop.start_tile.to_string().red().bold().to_string()
+            } else if pipe_loop.loop_elements.contains(&position) {
+                tile.to_string().green().to_string()
+            } else {
+                tile.to_string()
+            }
+        });
+        rendered.push_str(&format!("Loop size: {}", pipe_loop.size));
+
+        rendered
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct PipeLoop {
+    pub start_tile: Tile,
+    pub loop_elements: HashSet<Point2<usize>>,
+    pub size: usize,
+}
@@ -0,0 +1,37 @@
+use crate::geometry::Point2;
+use crate::grid::Grid;
+use crate::parser::{check_charset, check_rectangular, ParseError};
+use crate::year2023::day10::models::{Maze, Tile};
+
+impl From<char> for Tile {
+    fn from(value: char) -> Self {
+        match value {
+            '|' => Self::Vertical,
+            '-' => Self::Horizontal,
+            'L' => Self::BottomLeft,
+            'J' => Self::BottomRight,
+            '7' => Self::TopRight,
+            'F' => Self::TopLeft,
+            '.' => Self::Ground,
+            'S' => Self::Start,
+            _ => unreachable!(),
+        }
+    }
+}
+
+pub fn parse_input(input: String) -> Result<Maze, ParseError> {
+    check_charset(10, &input, "|-LJ7F.S")?;
+    check_rectangular(10, &input)?;
+
+    let tiles: Grid<Tile> = Grid::parse(&input);
+
+    // Extract starting position
+    let start = tiles
+        .find(|tile| *tile == Tile::Start)
+        .ok_or_else(|| ParseError::new(10, &input, 0, "a starting tile 'S'"))?;
+
+    Ok(Maze {
+        tiles,
+        start: Point2::from_position(start),
+    })
+}
@@ -0,0 +1,63 @@
+use crate::common::{test_examples_common, test_generated_input_common};
+use crate::models::AdventSolution;
+use crate::parser::ParseError;
+use crate::year2023::day10::parser::parse_input;
+use crate::year2023::day10::{Day10, REGISTRATION};
+
+#[test]
+fn test_parse_missing_start() {
+    assert_eq!(
+        parse_input(".....\n.F-7.\n.L-J.\n".to_string()).err(),
+        Some(ParseError {
+            day: 10,
+            line: 1,
+            column: 1,
+            expected: "a starting tile 'S'".to_string(),
+            snippet: ".....".to_string(),
+        })
+    );
+}
+
+/// Remove the ANSI color codes of a rendered text, which are only written when colors are enabled
+fn strip_colors(rendered: &str) -> String {
+    let mut stripped = String::new();
+    let mut rest = rendered;
+    while let Some(start) = rest.find('\x1b') {
+        stripped.push_str(&rest[..start]);
+        rest = &rest[start + rest[start..].find('m').unwrap() + 1..];
+    }
+    stripped.push_str(rest);
+
+    stripped
+}
+
+#[test]
+fn test_render() {
+    let mut solution = Day10::default();
+    assert_eq!(solution.render(), None);
+
+    solution
+        .parse("..F7.\n.FJ|.\nSJ.L7\n|F--J\nLJ...\n".to_string())
+        .unwrap();
+    assert_eq!(
+        solution.render().unwrap(),
+        "Grid:\n..┌┐.\n.┌┘│.\nS┘.└┐\n│┌──┘\n└┘...\nStart: (0, 2)\n"
+    );
+
+    // Once prepared, the start is replaced by the tile it stands for and the loop is highlighted
+    solution.prepare();
+    assert_eq!(
+        strip_colors(&solution.render().unwrap()),
+        "..┌┐.\n.┌┘│.\n┌┘.└┐\n│┌──┘\n└┘...\nLoop size: 16"
+    );
+}
+
+#[test]
+fn test_examples() {
+    test_examples_common(&REGISTRATION);
+}
+
+#[test]
+fn test_generated_input() {
+    test_generated_input_common::<Day10>();
+}
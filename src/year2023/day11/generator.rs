@@ -0,0 +1,13 @@
+use rand::Rng;
+
+/// Generate a `size` x `size` image with a galaxy on about 5% of the tiles
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            (0..size.max(1))
+                .map(|_| if rng.gen_bool(0.05) { '#' } else { '.' })
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
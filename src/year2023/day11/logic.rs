@@ -1,28 +1,22 @@
-use crate::day11::models::{Coordinates, SkyMap, SparseSkyMap, Tile};
+use crate::year2023::day11::models::{Coordinates, SkyMap, SparseSkyMap, Tile};
 
 /// We want to store the position of every star in a vec.
 /// We want to store the number of empty lines and empty column between them after that.
+#[allow(clippy::needless_range_loop)]
 pub fn prepare(data: &SkyMap) -> SparseSkyMap {
     // Get data size
-    let width = data.grid[0].len();
-    let height = data.grid.len();
+    let width = data.width();
+    let height = data.height();
 
     let galaxies: Vec<_> = data
-        .grid
         .iter()
-        .enumerate()
-        .flat_map(|(i_line, line)| {
-            line.iter()
-                .enumerate()
-                .filter(|(_, tile)| tile == &&Tile::Galaxy)
-                .map(move |(i_column, _)| Coordinates::new(i_line, i_column))
-        })
+        .filter(|(_, tile)| tile == &&Tile::Galaxy)
+        .map(|((i_line, i_column), _)| Coordinates::new(i_line, i_column))
         .collect();
 
     // Precompute the list of empty lines
     let empty_lines: Vec<_> = data
-        .grid
-        .iter()
+        .lines()
         .map(|line| line.iter().all(|tile| tile == &Tile::Empty))
         .map(|empty| empty as usize)
         .collect();
@@ -49,12 +43,9 @@ pub fn prepare(data: &SkyMap) -> SparseSkyMap {
     }
 
     // Do the same for the columns
-    let empty_columns: Vec<_> = (0..width)
-        .map(|i_column| {
-            (0..height)
-                .map(|i_line| &data.grid[i_line][i_column])
-                .all(|tile| tile == &Tile::Empty)
-        })
+    let empty_columns: Vec<_> = data
+        .columns()
+        .map(|mut column| column.all(|tile| tile == &Tile::Empty))
         .map(|empty| empty as usize)
         .collect();
 
@@ -101,7 +92,7 @@ pub fn solve_part_one(prepared_data: &SparseSkyMap) -> usize {
     total_distance
 }
 
-pub fn solve_part_two(prepared_data: &SparseSkyMap) -> usize {
+pub fn solve_part_two(prepared_data: &SparseSkyMap, expansion: usize) -> usize {
     // For every pair of star compute the distance between them
     let mut total_distance = 0;
     for i_star_1 in 0..prepared_data.galaxies.len() {
@@ -109,9 +100,9 @@ pub fn solve_part_two(prepared_data: &SparseSkyMap) -> usize {
             let star_1 = &prepared_data.galaxies[i_star_1];
             let star_2 = &prepared_data.galaxies[i_star_2];
             total_distance += star_2.column.abs_diff(star_1.column)
-                + prepared_data.empty_columns_count[star_1.column][star_2.column] * 999_999
+                + prepared_data.empty_columns_count[star_1.column][star_2.column] * (expansion - 1)
                 + star_2.line.abs_diff(star_1.line)
-                + prepared_data.empty_lines_count[star_1.line][star_2.line] * 999_999;
+                + prepared_data.empty_lines_count[star_1.line][star_2.line] * (expansion - 1);
         }
     }
 
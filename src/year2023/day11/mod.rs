@@ -0,0 +1,88 @@
+mod generator;
+mod logic;
+mod models;
+mod parser;
+
+#[cfg(test)]
+mod tests;
+
+use rand::rngs::StdRng;
+
+use crate::models::{AdventSolution, Answer};
+use crate::parameters::{Parameter, ParameterError, ParameterValue};
+use crate::parser::ParseError;
+use crate::registry::Registration;
+use crate::year2023::day11::generator::generate_input;
+use crate::year2023::day11::logic::{prepare, solve_part_one, solve_part_two};
+use crate::year2023::day11::models::{SkyMap, SparseSkyMap};
+use crate::year2023::day11::parser::parse_input;
+
+pub const REGISTRATION: Registration = Registration::new::<Day11>(2023, 11, "Cosmic Expansion", [true, true]);
+
+pub struct Day11 {
+    /// Size of an empty line or column in part two
+    expansion: usize,
+    parsed_data: Option<SkyMap>,
+    prepared_data: Option<SparseSkyMap>,
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Self {
+            expansion: 1_000_000,
+            parsed_data: None,
+            prepared_data: None,
+        }
+    }
+}
+
+impl AdventSolution for Day11 {
+    fn parse(&mut self, _data: String) -> Result<(), ParseError> {
+        self.parsed_data = Some(parse_input(_data)?);
+        Ok(())
+    }
+
+    fn prepare(&mut self) {
+        self.prepared_data = Some(prepare(self.parsed_data.as_ref().unwrap()));
+    }
+    fn solve_part_one(&self) -> Answer {
+        solve_part_one(self.prepared_data.as_ref().unwrap()).into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        solve_part_two(self.prepared_data.as_ref().unwrap(), self.expansion).into()
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![Parameter::new(
+            "expansion",
+            "Size of an empty line or column in part two",
+            ParameterValue::Unsigned(Self::default().expansion),
+        )]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: ParameterValue) -> Result<(), ParameterError> {
+        match (name, value) {
+            // An empty line or column cannot disappear
+            ("expansion", ParameterValue::Unsigned(0)) => {
+                return Err(ParameterError::InvalidValue {
+                    name: name.to_string(),
+                    value: "0".to_string(),
+                    expected: "a positive integer",
+                })
+            }
+            ("expansion", ParameterValue::Unsigned(value)) => self.expansion = value,
+            _ => unreachable!(),
+        }
+
+        Ok(())
+    }
+
+    fn generate_input(&self, size: usize, rng: &mut StdRng) -> Option<String> {
+        Some(generate_input(size, rng))
+    }
+
+    fn render(&self) -> Option<String> {
+        self.parsed_data.as_ref().map(|data| data.to_string())
+    }
+}
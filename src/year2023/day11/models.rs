@@ -1,5 +1,7 @@
 use std::fmt::{Display, Formatter};
 
+use crate::grid::Grid;
+
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub enum Tile {
     Empty,
@@ -15,23 +17,7 @@ impl Display for Tile {
     }
 }
 
-#[derive(Debug, Eq, PartialEq, Clone)]
-pub struct SkyMap {
-    pub grid: Vec<Vec<Tile>>,
-}
-
-impl Display for SkyMap {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        for line in &self.grid {
-            for tile in line {
-                write!(f, "{}", tile)?;
-            }
-            writeln!(f)?;
-        }
-
-        Ok(())
-    }
-}
+pub type SkyMap = Grid<Tile>;
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct Coordinates {
@@ -0,0 +1,20 @@
+use crate::grid::Grid;
+use crate::parser::{check_charset, check_rectangular, ParseError};
+use crate::year2023::day11::models::{SkyMap, Tile};
+
+impl From<char> for Tile {
+    fn from(value: char) -> Self {
+        match value {
+            '.' => Self::Empty,
+            '#' => Self::Galaxy,
+            _ => unreachable!(),
+        }
+    }
+}
+
+pub fn parse_input(input: String) -> Result<SkyMap, ParseError> {
+    check_charset(11, &input, ".#")?;
+    check_rectangular(11, &input)?;
+
+    Ok(Grid::parse(&input))
+}
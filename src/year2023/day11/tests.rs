@@ -0,0 +1,29 @@
+use crate::common::{test_examples_common, test_generated_input_common};
+use crate::models::AdventSolution;
+use crate::parameters::ParameterError;
+use crate::year2023::day11::{Day11, REGISTRATION};
+
+#[test]
+fn test_examples() {
+    test_examples_common(&REGISTRATION);
+}
+
+#[test]
+fn test_generated_input() {
+    test_generated_input_common::<Day11>();
+}
+
+#[test]
+fn test_expansion() {
+    let mut solution = Day11::default();
+    assert_eq!(solution.configure("expansion", "1"), Ok(()));
+    assert_eq!(
+        solution.configure("expansion", "0"),
+        Err(ParameterError::InvalidValue {
+            name: "expansion".to_string(),
+            value: "0".to_string(),
+            expected: "a positive integer",
+        })
+    );
+    assert_eq!(solution.expansion, 1);
+}
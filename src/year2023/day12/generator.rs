@@ -0,0 +1,30 @@
+use itertools::Itertools;
+use rand::Rng;
+
+/// Generate `size` records of up to 20 springs, each record has at least one arrangement
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            // Pick an arrangement with at least one damaged spring, then hide some springs
+            let length = rng.gen_range(1..=20);
+            let mut springs: Vec<_> = (0..length).map(|_| rng.gen_bool(0.5)).collect();
+            springs[rng.gen_range(0..length)] = true;
+            let groups = springs
+                .iter()
+                .group_by(|damaged| **damaged)
+                .into_iter()
+                .filter(|(damaged, _)| *damaged)
+                .map(|(_, group)| group.count())
+                .join(",");
+            let record: String = springs
+                .into_iter()
+                .map(|damaged| match (rng.gen_bool(0.4), damaged) {
+                    (true, _) => '?',
+                    (false, true) => '#',
+                    (false, false) => '.',
+                })
+                .collect();
+            format!("{record} {groups}\n")
+        })
+        .collect()
+}
@@ -1,4 +1,4 @@
-use crate::day12::models::{ConditionRecord, SpringStatus};
+use crate::year2023::day12::models::{ConditionRecord, SpringStatus};
 
 fn get_combination_count(
     memory: &mut Vec<Vec<usize>>,
@@ -71,6 +71,38 @@ pub fn solve_part_one(conditions_records: &[ConditionRecord]) -> usize {
         .sum()
 }
 
+/// Reference implementation of part one trying every arrangement of the unknown springs
+pub fn solve_part_one_bruteforce(conditions_records: &[ConditionRecord]) -> usize {
+    conditions_records
+        .iter()
+        .map(|record| {
+            let unknowns: Vec<_> = (0..record.spring_status.len())
+                .filter(|position| record.spring_status[*position] == SpringStatus::Unknown)
+                .collect();
+
+            (0..1_usize << unknowns.len())
+                .filter(|arrangement| {
+                    let mut damaged: Vec<_> = record
+                        .spring_status
+                        .iter()
+                        .map(|status| status == &SpringStatus::Damaged)
+                        .collect();
+                    for (bit, position) in unknowns.iter().enumerate() {
+                        damaged[*position] = arrangement >> bit & 1 == 1;
+                    }
+
+                    let groups: Vec<_> = damaged
+                        .split(|damaged| !damaged)
+                        .map(<[bool]>::len)
+                        .filter(|len| *len > 0)
+                        .collect();
+                    groups == record.spring_groups
+                })
+                .count()
+        })
+        .sum()
+}
+
 pub fn solve_part_two(conditions_records: &[ConditionRecord]) -> usize {
     // Clone condition records
     let mut conditions_records = conditions_records.to_vec();
@@ -9,7 +9,8 @@ use nom::multi::{many1, separated_list1};
 use nom::sequence::tuple;
 use nom::{IResult, Parser};
 
-use crate::day12::models::{ConditionRecord, SpringStatus};
+use crate::parser::{finish, ParseError};
+use crate::year2023::day12::models::{ConditionRecord, SpringStatus};
 
 fn parse_condition_record(input: &str) -> IResult<&str, ConditionRecord> {
     map_res(
@@ -40,11 +41,6 @@ fn parse_condition_record(input: &str) -> IResult<&str, ConditionRecord> {
     .parse(input)
 }
 
-pub fn parse_input(input: String) -> Vec<ConditionRecord> {
-    let (res, conditions_records) = many1(parse_condition_record).parse(&input).unwrap();
-    if !res.is_empty() {
-        panic!("Unable to fully parse the input: {}", res);
-    }
-
-    conditions_records
+pub fn parse_input(input: String) -> Result<Vec<ConditionRecord>, ParseError> {
+    finish(12, &input, many1(parse_condition_record).parse(&input))
 }
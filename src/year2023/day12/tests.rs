@@ -0,0 +1,17 @@
+use crate::common::{test_against_reference_common, test_examples_common, test_generated_input_common};
+use crate::year2023::day12::{Day12, REGISTRATION};
+
+#[test]
+fn test_examples() {
+    test_examples_common(&REGISTRATION);
+}
+
+#[test]
+fn test_generated_input() {
+    test_generated_input_common::<Day12>();
+}
+
+#[test]
+fn test_against_reference() {
+    test_against_reference_common::<Day12>(&[1, 2, 5], |_| {});
+}
@@ -0,0 +1,39 @@
+use itertools::Itertools;
+use rand::Rng;
+
+/// Generate `size` patterns of 3 to 17 lines and columns, each one with a line of reflection
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            let height = rng.gen_range(3..=17);
+            let width = rng.gen_range(3..=17);
+            let mut grid: Vec<Vec<bool>> = (0..height)
+                .map(|_| (0..width).map(|_| rng.gen_bool(0.5)).collect())
+                .collect();
+
+            // Mirror one side of the pattern on the other side of the reflection line
+            if rng.gen_bool(0.5) {
+                let reflection = rng.gen_range(1..height);
+                for offset in 0..reflection.min(height - reflection) {
+                    grid[reflection + offset] = grid[reflection - offset - 1].clone();
+                }
+            } else {
+                let reflection = rng.gen_range(1..width);
+                for line in grid.iter_mut() {
+                    for offset in 0..reflection.min(width - reflection) {
+                        line[reflection + offset] = line[reflection - offset - 1];
+                    }
+                }
+            }
+
+            grid.into_iter()
+                .map(|line| {
+                    line.into_iter()
+                        .map(|rock| if rock { '#' } else { '.' })
+                        .collect::<String>()
+                })
+                .join("\n")
+        })
+        .join("\n\n")
+        + "\n"
+}
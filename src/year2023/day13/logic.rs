@@ -1,4 +1,5 @@
-use crate::day13::models::Grid;
+use crate::grid::Grid;
+use crate::year2023::day13::models::Pattern;
 
 pub fn find_mirrored_lines(lines: &[u64], width: usize) -> Option<usize> {
     let mut positions: u64 = (1 << width) - 1;
@@ -84,24 +85,79 @@ pub fn find_mirrored_lines_with_replacement(lines: &[u64], width: usize) -> Opti
     None
 }
 
-pub fn solve_part_one(data: &[Grid]) -> usize {
+pub fn solve_part_one(data: &[Pattern]) -> usize {
     data.iter()
-        .filter_map(|grid| find_mirrored_lines(&grid.lines, grid.width))
+        .filter_map(|pattern| find_mirrored_lines(&pattern.lines, pattern.tiles.width()))
         .sum::<usize>()
         + 100
             * data
                 .iter()
-                .filter_map(|grid| find_mirrored_lines(&grid.columns, grid.height))
+                .filter_map(|pattern| find_mirrored_lines(&pattern.columns, pattern.tiles.height()))
                 .sum::<usize>()
 }
 
-pub fn solve_part_two(data: &[Grid]) -> usize {
+pub fn solve_part_two(data: &[Pattern]) -> usize {
     data.iter()
-        .filter_map(|grid| find_mirrored_lines_with_replacement(&grid.lines, grid.width))
+        .filter_map(|pattern| find_mirrored_lines_with_replacement(&pattern.lines, pattern.tiles.width()))
         .sum::<usize>()
         + 100
             * data
                 .iter()
-                .filter_map(|grid| find_mirrored_lines_with_replacement(&grid.columns, grid.height))
+                .filter_map(|pattern| find_mirrored_lines_with_replacement(&pattern.columns, pattern.tiles.height()))
                 .sum::<usize>()
 }
+
+/// Every position of a vertical line of reflection of the pattern, comparing the tiles one by one
+fn mirror_positions(pattern: &Grid<bool>) -> Vec<usize> {
+    let width = pattern.width();
+    (1..width)
+        .filter(|position| {
+            pattern.lines().all(|line| {
+                (0..*position.min(&(width - position)))
+                    .all(|offset| line[position - offset - 1] == line[position + offset])
+            })
+        })
+        .collect()
+}
+
+/// Try every smudge, in the same order as `find_mirrored_lines_with_replacement`,
+/// until one of them creates a new line of reflection
+fn smudged_mirror_position(mut pattern: Grid<bool>) -> Option<usize> {
+    let original = mirror_positions(&pattern).first().copied();
+
+    for line in 0..pattern.height() {
+        for column in 0..pattern.width() {
+            pattern[(line, column)] = !pattern[(line, column)];
+            let position = mirror_positions(&pattern)
+                .into_iter()
+                .find(|position| Some(*position) != original);
+            pattern[(line, column)] = !pattern[(line, column)];
+
+            if position.is_some() {
+                return position;
+            }
+        }
+    }
+
+    None
+}
+
+/// Reference implementation of part one working on the tiles instead of bitmasks
+pub fn solve_part_one_bruteforce(data: &[Pattern]) -> usize {
+    data.iter()
+        .map(|pattern| {
+            mirror_positions(&pattern.tiles).first().unwrap_or(&0)
+                + 100 * mirror_positions(&pattern.tiles.transpose()).first().unwrap_or(&0)
+        })
+        .sum()
+}
+
+/// Reference implementation of part two working on the tiles instead of bitmasks
+pub fn solve_part_two_bruteforce(data: &[Pattern]) -> usize {
+    data.iter()
+        .map(|pattern| {
+            smudged_mirror_position(pattern.tiles.clone()).unwrap_or(0)
+                + 100 * smudged_mirror_position(pattern.tiles.transpose()).unwrap_or(0)
+        })
+        .sum()
+}
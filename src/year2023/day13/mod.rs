@@ -0,0 +1,53 @@
+mod generator;
+mod logic;
+mod models;
+mod parser;
+
+#[cfg(test)]
+mod tests;
+
+use rand::rngs::StdRng;
+
+use crate::models::{AdventSolution, Answer};
+use crate::parser::ParseError;
+use crate::registry::Registration;
+use crate::year2023::day13::generator::generate_input;
+use crate::year2023::day13::logic::{
+    solve_part_one, solve_part_one_bruteforce, solve_part_two, solve_part_two_bruteforce,
+};
+use crate::year2023::day13::models::Pattern;
+use crate::year2023::day13::parser::parse_input;
+
+pub const REGISTRATION: Registration = Registration::new::<Day13>(2023, 13, "Point of Incidence", [true, true]);
+
+#[derive(Default)]
+pub struct Day13 {
+    parsed_data: Option<Vec<Pattern>>,
+}
+
+impl AdventSolution for Day13 {
+    fn parse(&mut self, _data: String) -> Result<(), ParseError> {
+        self.parsed_data = Some(parse_input(_data)?);
+        Ok(())
+    }
+
+    fn solve_part_one(&self) -> Answer {
+        solve_part_one(self.parsed_data.as_ref().unwrap()).into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        solve_part_two(self.parsed_data.as_ref().unwrap()).into()
+    }
+
+    fn solve_part_one_reference(&self) -> Option<Answer> {
+        Some(solve_part_one_bruteforce(self.parsed_data.as_ref().unwrap()).into())
+    }
+
+    fn solve_part_two_reference(&self) -> Option<Answer> {
+        Some(solve_part_two_bruteforce(self.parsed_data.as_ref().unwrap()).into())
+    }
+
+    fn generate_input(&self, size: usize, rng: &mut StdRng) -> Option<String> {
+        Some(generate_input(size, rng))
+    }
+}
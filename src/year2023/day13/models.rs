@@ -0,0 +1,11 @@
+use crate::grid::Grid;
+
+/// Pattern of ash and rocks, `true` for a rock
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Pattern {
+    pub tiles: Grid<bool>,
+    /// Lines of the pattern as bitmasks, the first column being the most significant bit
+    pub lines: Vec<u64>,
+    /// Columns of the pattern as bitmasks, the first line being the most significant bit
+    pub columns: Vec<u64>,
+}
@@ -0,0 +1,48 @@
+use crate::grid::Grid;
+use crate::parser::{check_charset, ParseError};
+use crate::year2023::day13::models::Pattern;
+
+fn to_bitmask<'a>(tiles: impl Iterator<Item = &'a bool>) -> u64 {
+    tiles.fold(0, |acc, rock| (acc << 1) + (*rock as u64))
+}
+
+impl Pattern {
+    pub fn new(tiles: Grid<bool>) -> Self {
+        let lines = tiles.lines().map(|line| to_bitmask(line.iter())).collect();
+        let columns = tiles.columns().map(to_bitmask).collect();
+
+        Self { tiles, lines, columns }
+    }
+}
+
+pub fn parse_input(input: String) -> Result<Vec<Pattern>, ParseError> {
+    check_charset(13, &input, ".#")?;
+
+    let mut patterns: Vec<Pattern> = vec![];
+
+    let mut last_pattern = vec![];
+    let mut width: usize = 0;
+
+    for line in input.lines() {
+        if line.is_empty() {
+            patterns.push(Pattern::new(Grid::from_lines(last_pattern)));
+            last_pattern = vec![];
+            width = 0;
+        } else {
+            // Lines are stored as bitmasks, they must fit in an u64 and have the same width
+            if line.len() >= 64 || (width != 0 && line.len() != width) {
+                return Err(ParseError::at(
+                    13,
+                    &input,
+                    line,
+                    "a line matching the grid width (at most 63 characters)",
+                ));
+            }
+            width = line.len();
+            last_pattern.push(line.chars().map(|char| char == '#').collect());
+        }
+    }
+    patterns.push(Pattern::new(Grid::from_lines(last_pattern)));
+
+    Ok(patterns)
+}
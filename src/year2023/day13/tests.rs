@@ -0,0 +1,43 @@
+use crate::common::{test_against_reference_common, test_examples_common, test_generated_input_common};
+use crate::parser::ParseError;
+use crate::year2023::day13::parser::parse_input;
+use crate::year2023::day13::{Day13, REGISTRATION};
+
+#[test]
+fn test_parse_error() {
+    assert_eq!(
+        parse_input("#.#\n.O.\n".to_string()).err(),
+        Some(ParseError {
+            day: 13,
+            line: 2,
+            column: 2,
+            expected: "one of ['.', '#']".to_string(),
+            snippet: ".O.".to_string(),
+        })
+    );
+    assert_eq!(
+        parse_input("#.#\n..\n".to_string()).err(),
+        Some(ParseError {
+            day: 13,
+            line: 2,
+            column: 1,
+            expected: "a line matching the grid width (at most 63 characters)".to_string(),
+            snippet: "..".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_examples() {
+    test_examples_common(&REGISTRATION);
+}
+
+#[test]
+fn test_generated_input() {
+    test_generated_input_common::<Day13>();
+}
+
+#[test]
+fn test_against_reference() {
+    test_against_reference_common::<Day13>(&[1, 2, 5], |_| {});
+}
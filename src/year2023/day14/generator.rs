@@ -0,0 +1,17 @@
+use rand::Rng;
+
+/// Generate a `size` x `size` platform of round and cube-shaped rocks
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            (0..size.max(1))
+                .map(|_| match rng.gen_range(0..10) {
+                    0..=1 => 'O',
+                    2 => '#',
+                    _ => '.',
+                })
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
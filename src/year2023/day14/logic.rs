@@ -0,0 +1,60 @@
+use crate::cycle::state_at;
+use crate::year2023::day14::models::{Platform, Tile};
+
+fn compute_load(platform: &Platform) -> usize {
+    platform
+        .lines()
+        .enumerate()
+        .map(|(i_line, line)| (platform.height() - i_line) * line.iter().filter(|tile| **tile == Tile::Round).count())
+        .sum()
+}
+
+fn tilt_north(platform: &mut Platform) {
+    // Iterate column by column, from the left to the right
+    for i_column in 0..platform.width() {
+        for i_line in 0..platform.height() {
+            // We need to move the tile
+            if platform[(i_line, i_column)] == Tile::Round {
+                let mut last_free = i_line;
+
+                // Move it in the column until we find an obstacle
+                for i_line_2 in (0..i_line).rev() {
+                    if platform[(i_line_2, i_column)] == Tile::Empty {
+                        // No obstacle, update the last_free pointer
+                        last_free = i_line_2;
+                    } else {
+                        // Found an obstacle, break the loop
+                        break;
+                    }
+                }
+
+                // Perform the swap
+                platform[(i_line, i_column)] = Tile::Empty;
+                platform[(last_free, i_column)] = Tile::Round;
+            }
+        }
+    }
+}
+
+/// Tilt the platform north, west, south and east.
+/// Once tilted north, the platform is rotated clockwise so that its west side is the next one tilted north.
+fn spin_cycle(platform: &Platform) -> Platform {
+    let mut platform = platform.clone();
+    for _ in 0..4 {
+        tilt_north(&mut platform);
+        platform = platform.rotate_clockwise();
+    }
+
+    platform
+}
+
+pub fn solve_part_one(platform: &Platform) -> usize {
+    let mut platform = platform.clone();
+    tilt_north(&mut platform);
+    compute_load(&platform)
+}
+
+pub fn solve_part_two(platform: &Platform, target: usize) -> usize {
+    // The platform ends up looping, skip the repeated spin cycles
+    compute_load(&state_at(platform.clone(), spin_cycle, target))
+}
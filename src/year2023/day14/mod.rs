@@ -0,0 +1,75 @@
+mod generator;
+mod logic;
+mod models;
+mod parser;
+
+#[cfg(test)]
+mod tests;
+
+use rand::rngs::StdRng;
+
+use crate::models::{AdventSolution, Answer};
+use crate::parameters::{Parameter, ParameterError, ParameterValue};
+use crate::parser::ParseError;
+use crate::registry::Registration;
+use crate::year2023::day14::generator::generate_input;
+use crate::year2023::day14::logic::{solve_part_one, solve_part_two};
+use crate::year2023::day14::models::Platform;
+use crate::year2023::day14::parser::parse_input;
+
+pub const REGISTRATION: Registration = Registration::new::<Day14>(2023, 14, "Parabolic Reflector Dish", [true, true]);
+
+pub struct Day14 {
+    /// Number of spin cycles of part two
+    cycles: usize,
+    parsed_data: Option<Platform>,
+}
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Self {
+            cycles: 1_000_000_000,
+            parsed_data: None,
+        }
+    }
+}
+
+impl AdventSolution for Day14 {
+    fn parse(&mut self, _data: String) -> Result<(), ParseError> {
+        self.parsed_data = Some(parse_input(_data)?);
+        Ok(())
+    }
+
+    fn solve_part_one(&self) -> Answer {
+        solve_part_one(self.parsed_data.as_ref().unwrap()).into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        solve_part_two(self.parsed_data.as_ref().unwrap(), self.cycles).into()
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![Parameter::new(
+            "cycles",
+            "Number of spin cycles of part two",
+            ParameterValue::Unsigned(Self::default().cycles),
+        )]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: ParameterValue) -> Result<(), ParameterError> {
+        match (name, value) {
+            ("cycles", ParameterValue::Unsigned(value)) => self.cycles = value,
+            _ => unreachable!(),
+        }
+
+        Ok(())
+    }
+
+    fn generate_input(&self, size: usize, rng: &mut StdRng) -> Option<String> {
+        Some(generate_input(size, rng))
+    }
+
+    fn render(&self) -> Option<String> {
+        self.parsed_data.as_ref().map(|data| data.to_string())
+    }
+}
@@ -1,5 +1,7 @@
 use std::fmt::{Display, Formatter};
 
+use crate::grid::Grid;
+
 #[derive(Eq, PartialEq, Debug, Clone, Hash)]
 pub enum Tile {
     Empty,
@@ -21,22 +23,4 @@ impl Display for Tile {
     }
 }
 
-#[derive(Eq, PartialEq, Debug, Clone, Hash)]
-pub struct Platform {
-    pub grid: Vec<Vec<Tile>>,
-    pub width: usize,
-    pub height: usize,
-}
-
-impl Display for Platform {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        for line in &self.grid {
-            for tile in line {
-                write!(f, "{}", tile)?;
-            }
-            writeln!(f)?;
-        }
-
-        Ok(())
-    }
-}
+pub type Platform = Grid<Tile>;
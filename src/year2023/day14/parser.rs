@@ -0,0 +1,21 @@
+use crate::grid::Grid;
+use crate::parser::{check_charset, check_rectangular, ParseError};
+use crate::year2023::day14::models::{Platform, Tile};
+
+impl From<char> for Tile {
+    fn from(value: char) -> Self {
+        match value {
+            '.' => Self::Empty,
+            'O' => Self::Round,
+            '#' => Self::Square,
+            _ => unreachable!(),
+        }
+    }
+}
+
+pub fn parse_input(input: String) -> Result<Platform, ParseError> {
+    check_charset(14, &input, ".O#")?;
+    check_rectangular(14, &input)?;
+
+    Ok(Grid::parse(&input))
+}
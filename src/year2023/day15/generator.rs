@@ -0,0 +1,26 @@
+use itertools::Itertools;
+use rand::Rng;
+
+/// Generate `size` steps using a set of about `size / 4` labels
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    let size = size.max(1);
+    let labels: Vec<String> = (0..size.div_ceil(4))
+        .map(|_| {
+            (0..rng.gen_range(1..=6))
+                .map(|_| char::from(b'a' + rng.gen_range(0..26)))
+                .collect()
+        })
+        .collect();
+
+    (0..size)
+        .map(|_| {
+            let label = &labels[rng.gen_range(0..labels.len())];
+            if rng.gen_bool(0.3) {
+                format!("{label}-")
+            } else {
+                format!("{label}={}", rng.gen_range(1..=9))
+            }
+        })
+        .join(",")
+        + "\n"
+}
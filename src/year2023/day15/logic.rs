@@ -1,4 +1,4 @@
-use crate::day15::models::{Action, BoxLens, Step};
+use crate::year2023::day15::models::{Action, BoxLens, Step};
 
 fn compute_string_hash(data: &str) -> usize {
     data.chars().fold(0, |acc, char| (acc + char as usize) * 17 % 256)
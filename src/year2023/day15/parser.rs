@@ -9,7 +9,8 @@ use nom::multi::separated_list1;
 use nom::sequence::{terminated, tuple};
 use nom::{IResult, Parser};
 
-use crate::day15::models::{Action, Step};
+use crate::parser::{finish, ParseError};
+use crate::year2023::day15::models::{Action, Step};
 
 fn parse_remove(input: &str) -> IResult<&str, Action> {
     map(tag("-"), |_| Action::Remove).parse(input)
@@ -34,13 +35,10 @@ fn parse_step(input: &str) -> IResult<&str, Step> {
     .parse(input)
 }
 
-pub fn parse_input(input: String) -> Vec<Step> {
-    let (res, games) = terminated(separated_list1(tag(","), parse_step), opt(line_ending))
-        .parse(&input)
-        .unwrap();
-    if !res.is_empty() {
-        panic!("Unable to fully parse the input: {}", res);
-    }
-
-    games
+pub fn parse_input(input: String) -> Result<Vec<Step>, ParseError> {
+    finish(
+        15,
+        &input,
+        terminated(separated_list1(tag(","), parse_step), opt(line_ending)).parse(&input),
+    )
 }
@@ -0,0 +1,19 @@
+use rand::Rng;
+
+/// Generate a `size` x `size` contraption with mirrors and splitters on about 10% of the tiles
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            (0..size.max(1))
+                .map(|_| match rng.gen_range(0..40) {
+                    0 => '/',
+                    1 => '\\',
+                    2 => '-',
+                    3 => '|',
+                    _ => '.',
+                })
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
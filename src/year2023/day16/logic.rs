@@ -0,0 +1,94 @@
+use std::cmp::max;
+
+use crate::geometry::Direction;
+use crate::year2023::day16::models::{Contraption, Tile, TileContent};
+
+impl Tile {
+    /// Directions of the beams leaving the tile, a splitter creating a second beam
+    pub fn get_output_directions(&self, direction: Direction) -> (Direction, Option<Direction>) {
+        match (&self.content, direction) {
+            (TileContent::Empty, direction) => (direction, None),
+            (TileContent::Mirror, Direction::Right | Direction::Left) => (direction.turn_left(), None),
+            (TileContent::Mirror, Direction::Up | Direction::Down) => (direction.turn_right(), None),
+            (TileContent::AntiMirror, Direction::Right | Direction::Left) => (direction.turn_right(), None),
+            (TileContent::AntiMirror, Direction::Up | Direction::Down) => (direction.turn_left(), None),
+            (TileContent::HorizontalSplitter, Direction::Right | Direction::Left) => (direction, None),
+            (TileContent::HorizontalSplitter, Direction::Up | Direction::Down) => {
+                (Direction::Left, Some(Direction::Right))
+            }
+            (TileContent::VerticalSplitter, Direction::Up | Direction::Down) => (direction, None),
+            (TileContent::VerticalSplitter, Direction::Right | Direction::Left) => {
+                (Direction::Up, Some(Direction::Down))
+            }
+        }
+    }
+}
+
+fn simulate_and_get_energized_tile_count(
+    contraption: &Contraption,
+    line: usize,
+    column: usize,
+    direction: Direction,
+) -> u32 {
+    // We need to modify the contraption
+    let mut contraption = contraption.clone();
+
+    // Make beam progress
+    let mut queue = vec![];
+    queue.push((line, column, direction));
+
+    while let Some((line, column, direction)) = queue.pop() {
+        let tile = &mut contraption[(line, column)];
+
+        // If the contraption already has a beam in the current tile, return early
+        if tile.contains_beam(direction) {
+            continue;
+        }
+
+        // Update the current tile to add the beam
+        tile.insert_beam(direction);
+
+        // Get output directions and make beam progress further
+        let (first_direction, second_direction) = tile.get_output_directions(direction);
+        for direction in std::iter::once(first_direction).chain(second_direction) {
+            if let Some((line, column)) = contraption.neighbor((line, column), direction.offset()) {
+                queue.push((line, column, direction))
+            }
+        }
+    }
+
+    // Compute the number of energized tiles
+    contraption.cells().iter().map(|tile| tile.is_energized() as u32).sum()
+}
+
+pub fn solve_part_one(contraption: &Contraption) -> u32 {
+    simulate_and_get_energized_tile_count(contraption, 0, 0, Direction::Right)
+}
+
+pub fn solve_part_two(contraption: &Contraption) -> u32 {
+    (0..contraption.width())
+        .map(|starting_column| {
+            max(
+                simulate_and_get_energized_tile_count(contraption, 0, starting_column, Direction::Down),
+                simulate_and_get_energized_tile_count(
+                    contraption,
+                    contraption.height() - 1,
+                    starting_column,
+                    Direction::Up,
+                ),
+            )
+        })
+        .chain((0..contraption.height()).map(|starting_line| {
+            max(
+                simulate_and_get_energized_tile_count(contraption, starting_line, 0, Direction::Right),
+                simulate_and_get_energized_tile_count(
+                    contraption,
+                    starting_line,
+                    contraption.width() - 1,
+                    Direction::Left,
+                ),
+            )
+        }))
+        .max()
+        .unwrap()
+}
@@ -0,0 +1,65 @@
+use std::fmt::{Display, Formatter};
+
+use crate::geometry::Direction;
+use crate::grid::Grid;
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum TileContent {
+    Empty,
+    Mirror,
+    AntiMirror,
+    HorizontalSplitter,
+    VerticalSplitter,
+}
+
+impl Display for TileContent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                TileContent::Empty => ".",
+                TileContent::Mirror => "/",
+                TileContent::AntiMirror => r"\",
+                TileContent::HorizontalSplitter => "-",
+                TileContent::VerticalSplitter => "|",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Tile {
+    pub content: TileContent,
+    /// Directions of the beams that went through the tile, one bit per direction
+    pub beams: u8,
+}
+
+impl Tile {
+    pub fn new(content: TileContent) -> Self {
+        Self { content, beams: 0 }
+    }
+
+    #[inline(always)]
+    pub fn contains_beam(&self, direction: Direction) -> bool {
+        self.beams & (1 << direction as u8) != 0
+    }
+
+    #[inline(always)]
+    pub fn insert_beam(&mut self, direction: Direction) {
+        self.beams |= 1 << direction as u8
+    }
+
+    #[inline(always)]
+    pub fn is_energized(&self) -> bool {
+        self.beams != 0
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.content)
+    }
+}
+
+pub type Contraption = Grid<Tile>;
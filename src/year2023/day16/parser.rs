@@ -1,4 +1,6 @@
-use crate::day16::models::{Contraption, Tile, TileContent};
+use crate::grid::Grid;
+use crate::parser::{check_charset, check_rectangular, ParseError};
+use crate::year2023::day16::models::{Contraption, Tile, TileContent};
 
 impl From<char> for TileContent {
     fn from(value: char) -> Self {
@@ -19,11 +21,9 @@ impl From<char> for Tile {
     }
 }
 
-pub fn parse_input(input: String) -> Contraption {
-    Contraption::new(
-        input
-            .lines()
-            .map(|line| line.chars().map(From::from).collect())
-            .collect(),
-    )
+pub fn parse_input(input: String) -> Result<Contraption, ParseError> {
+    check_charset(16, &input, r"./\-|")?;
+    check_rectangular(16, &input)?;
+
+    Ok(Grid::parse(&input))
 }
@@ -0,0 +1,13 @@
+use rand::Rng;
+
+/// Generate a `size` x `size` map of heat losses between 1 and 9
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            (0..size.max(1))
+                .map(|_| char::from(b'0' + rng.gen_range(1..=9)))
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
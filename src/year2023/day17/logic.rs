@@ -3,7 +3,8 @@ use std::collections::VecDeque;
 use bucket_queue::{BucketQueue, FirstInFirstOutQueue};
 use ndarray::{Array4, Axis};
 
-use crate::day17::models::{Direction, Map};
+use crate::geometry::Direction;
+use crate::year2023::day17::models::Map;
 
 #[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
 struct VisitNext {
@@ -30,8 +31,8 @@ impl VisitNext {
 /// The algorithm is a modified version of a Dijkstra algorithm.
 fn find_shortest_path(map: &Map, min_distance: usize, max_distance: usize) -> usize {
     // Create structure to support the algorithm
-    let mut visited = Array4::<bool>::default((map.height, map.width, 4, max_distance + 1));
-    let mut scores = Array4::<usize>::from_elem((map.height, map.width, 4, max_distance + 1), usize::MAX);
+    let mut visited = Array4::<bool>::default((map.height(), map.width(), 4, max_distance + 1));
+    let mut scores = Array4::<usize>::from_elem((map.height(), map.width(), 4, max_distance + 1), usize::MAX);
     let mut visit_next = BucketQueue::<VecDeque<_>>::new();
     // let mut predecessor = HashMap::new();
 
@@ -41,7 +42,7 @@ fn find_shortest_path(map: &Map, min_distance: usize, max_distance: usize) -> us
     scores[[0, 0, 2, 0]] = 0;
     scores[[0, 0, 3, 0]] = 0;
     visit_next.enqueue(VisitNext::new(0, 0, 0, Direction::Right, 0), 0);
-    visit_next.enqueue(VisitNext::new(0, 0, 0, Direction::Bot, 0), 0);
+    visit_next.enqueue(VisitNext::new(0, 0, 0, Direction::Down, 0), 0);
 
     while let Some(visit) = visit_next.dequeue_min() {
         let line = visit.line;
@@ -52,72 +53,21 @@ fn find_shortest_path(map: &Map, min_distance: usize, max_distance: usize) -> us
             continue;
         }
 
-        // Up
-        if line > 0 {
-            visit_neighbor(
-                map,
-                min_distance,
-                max_distance,
-                &mut visited,
-                &mut scores,
-                &mut visit_next,
-                &visit,
-                line - 1,
-                column,
-                Direction::Up,
-                Direction::Bot,
-            );
-        }
-
-        // Left
-        if column > 0 {
-            visit_neighbor(
-                map,
-                min_distance,
-                max_distance,
-                &mut visited,
-                &mut scores,
-                &mut visit_next,
-                &visit,
-                line,
-                column - 1,
-                Direction::Left,
-                Direction::Right,
-            );
-        }
-
-        // Bot
-        if line < map.height - 1 {
-            visit_neighbor(
-                map,
-                min_distance,
-                max_distance,
-                &mut visited,
-                &mut scores,
-                &mut visit_next,
-                &visit,
-                line + 1,
-                column,
-                Direction::Bot,
-                Direction::Up,
-            );
-        }
-
-        // Right
-        if column < map.width - 1 {
-            visit_neighbor(
-                map,
-                min_distance,
-                max_distance,
-                &mut visited,
-                &mut scores,
-                &mut visit_next,
-                &visit,
-                line,
-                column + 1,
-                Direction::Right,
-                Direction::Left,
-            );
+        for new_direction in Direction::ALL {
+            if let Some((new_line, new_column)) = map.neighbor((line, column), new_direction.offset()) {
+                visit_neighbor(
+                    map,
+                    min_distance,
+                    max_distance,
+                    &mut visited,
+                    &mut scores,
+                    &mut visit_next,
+                    &visit,
+                    new_line,
+                    new_column,
+                    new_direction,
+                );
+            }
         }
 
         visited[[line, column, visit.direction as usize, visit.direction_steps]] = true
@@ -125,8 +75,8 @@ fn find_shortest_path(map: &Map, min_distance: usize, max_distance: usize) -> us
 
     // // Get the winning path
     // let winning = scores
-    //     .index_axis(Axis(0), map.height - 1)
-    //     .index_axis(Axis(0), map.width - 1)
+    //     .index_axis(Axis(0), map.height() - 1)
+    //     .index_axis(Axis(0), map.width() - 1)
     //     .indexed_iter()
     //     .filter_map(|((direction, direction_steps), score)| {
     //         if direction_steps >= min_distance {
@@ -140,7 +90,7 @@ fn find_shortest_path(map: &Map, min_distance: usize, max_distance: usize) -> us
     //
     // // Reconstruct the path
     // let mut path = vec![];
-    // let mut node = (map.height - 1, map.width - 1, winning.1, winning.2);
+    // let mut node = (map.height() - 1, map.width() - 1, winning.1, winning.2);
     // loop {
     //     // Add the node to the path
     //     path.push(node);
@@ -155,13 +105,13 @@ fn find_shortest_path(map: &Map, min_distance: usize, max_distance: usize) -> us
     // }
     // path.reverse();
     //
-    // map.display_path(&path);
+    // display_path(map, &path);
     //
     // winning.0
 
     scores
-        .index_axis(Axis(0), map.height - 1)
-        .index_axis(Axis(0), map.width - 1)
+        .index_axis(Axis(0), map.height() - 1)
+        .index_axis(Axis(0), map.width() - 1)
         .indexed_iter()
         .filter_map(|((_, direction_steps), score)| {
             if direction_steps >= min_distance {
@@ -187,9 +137,9 @@ fn visit_neighbor(
     new_line: usize,
     new_colum: usize,
     new_direction: Direction,
-    forbidden_direction: Direction,
 ) {
-    if visit.direction != forbidden_direction
+    // The path cannot go backwards
+    if visit.direction != new_direction.opposite()
         && ((visit.direction != new_direction && visit.direction_steps >= min_distance)
             || (visit.direction == new_direction && visit.direction_steps < max_distance))
     {
@@ -200,7 +150,7 @@ fn visit_neighbor(
         };
 
         if !visited[[new_line, new_colum, new_direction as usize, direction_steps]] {
-            let next_score = visit.score + map.grid[new_line][new_colum] as usize;
+            let next_score = visit.score + map[(new_line, new_colum)] as usize;
             if next_score < scores[[new_line, new_colum, new_direction as usize, direction_steps]] {
                 scores[[new_line, new_colum, new_direction as usize, direction_steps]] = next_score;
                 visit_next.enqueue(
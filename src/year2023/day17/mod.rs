@@ -0,0 +1,47 @@
+mod generator;
+mod logic;
+mod models;
+mod parser;
+
+#[cfg(test)]
+mod tests;
+
+use rand::rngs::StdRng;
+
+use crate::models::{AdventSolution, Answer};
+use crate::parser::ParseError;
+use crate::registry::Registration;
+use crate::year2023::day17::generator::generate_input;
+use crate::year2023::day17::logic::{solve_part_one, solve_part_two};
+use crate::year2023::day17::models::Map;
+use crate::year2023::day17::parser::parse_input;
+
+pub const REGISTRATION: Registration = Registration::new::<Day17>(2023, 17, "Clumsy Crucible", [true, true]);
+
+#[derive(Default)]
+pub struct Day17 {
+    parsed_data: Option<Map>,
+}
+
+impl AdventSolution for Day17 {
+    fn parse(&mut self, _data: String) -> Result<(), ParseError> {
+        self.parsed_data = Some(parse_input(_data)?);
+        Ok(())
+    }
+
+    fn solve_part_one(&self) -> Answer {
+        solve_part_one(self.parsed_data.as_ref().unwrap()).into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        solve_part_two(self.parsed_data.as_ref().unwrap()).into()
+    }
+
+    fn generate_input(&self, size: usize, rng: &mut StdRng) -> Option<String> {
+        Some(generate_input(size, rng))
+    }
+
+    fn render(&self) -> Option<String> {
+        self.parsed_data.as_ref().map(|data| data.to_string())
+    }
+}
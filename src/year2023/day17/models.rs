@@ -0,0 +1,57 @@
+use std::collections::{HashMap, HashSet};
+
+use colored::Colorize;
+
+use crate::grid::Grid;
+
+/// Heat loss of every block of the map
+pub type Map = Grid<u32>;
+
+#[allow(dead_code)]
+pub fn display_path(map: &Map, path: &[(usize, usize, usize, usize)]) {
+    // Convert the path to a hashset
+    let path_set: HashSet<_> = path.iter().map(|(line, column, _, _)| (*line, *column)).collect();
+
+    print!(
+        "{}",
+        map.render(|position, block| {
+            if path_set.contains(&position) {
+                block.to_string().green().to_string()
+            } else {
+                block.to_string()
+            }
+        })
+    );
+}
+
+#[allow(dead_code)]
+pub fn display_predecessor(map: &Map, path: &[(usize, usize)], predecessor: &HashMap<(usize, usize), (usize, usize)>) {
+    // Convert the path to a hashset
+    let path_set: HashSet<_> = path.iter().cloned().collect();
+
+    print!(
+        "{}",
+        map.render(|(line, column), block| {
+            let Some(pred) = predecessor.get(&(line, column)) else {
+                return block.to_string();
+            };
+
+            let to_display = if pred.0 + 1 == line {
+                "v"
+            } else if pred.1 + 1 == column {
+                ">"
+            } else if pred.0 == line + 1 {
+                "^"
+            } else if pred.1 == column + 1 {
+                "<"
+            } else {
+                unreachable!()
+            };
+            if path_set.contains(&(line, column)) {
+                to_display.green().to_string()
+            } else {
+                to_display.to_string()
+            }
+        })
+    );
+}
@@ -0,0 +1,12 @@
+use crate::grid::Grid;
+use crate::parser::{check_charset, check_rectangular, ParseError};
+use crate::year2023::day17::models::Map;
+
+static ZERO_VALUE: u32 = '0' as u32;
+
+pub fn parse_input(input: String) -> Result<Map, ParseError> {
+    check_charset(17, &input, "0123456789")?;
+    check_rectangular(17, &input)?;
+
+    Ok(Grid::parse_with(&input, |char| char as u32 - ZERO_VALUE))
+}
@@ -0,0 +1,55 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Create the instructions of a lagoon shaped like a histogram of `columns` columns.
+/// The outline starts at the bottom left corner, goes up, follows the top of the columns to the right,
+/// then goes back to the start along the bottom of the histogram.
+/// Consecutive columns never have the same height so that the number of instructions only depends on `columns`.
+fn histogram_outline(columns: usize, max_distance: i64, rng: &mut impl Rng) -> Vec<(char, i64)> {
+    let mut heights: Vec<i64> = vec![rng.gen_range(1..=max_distance)];
+    for _ in 1..columns {
+        let previous = heights[heights.len() - 1];
+        let height = (1..=max_distance)
+            .filter(|height| *height != previous)
+            .collect::<Vec<_>>()
+            .choose(rng)
+            .copied()
+            .unwrap_or(previous + 1);
+        heights.push(height);
+    }
+    let widths: Vec<i64> = (0..columns).map(|_| rng.gen_range(1..=max_distance)).collect();
+
+    let mut instructions = vec![('U', heights[0]), ('R', widths[0])];
+    for column in 1..columns {
+        let change = heights[column] - heights[column - 1];
+        instructions.push((if change > 0 { 'U' } else { 'D' }, change.abs()));
+        instructions.push(('R', widths[column]));
+    }
+    instructions.push(('D', heights[columns - 1]));
+    instructions.push(('L', widths.iter().sum()));
+
+    instructions
+}
+
+/// Generate a dig plan of `2 * size + 2` instructions describing two simple closed outlines,
+/// one for each part
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    let columns = size.max(1);
+    let part_one = histogram_outline(columns, 10, rng);
+    // Distances of part two are encoded on 5 hexadecimal digits
+    let part_two = histogram_outline(columns, 0xFFFFF / (columns as i64 + 1), rng);
+
+    part_one
+        .into_iter()
+        .zip(part_two)
+        .map(|((direction, distance), (direction_2, distance_2))| {
+            let direction_2 = match direction_2 {
+                'R' => 0,
+                'D' => 1,
+                'L' => 2,
+                _ => 3,
+            };
+            format!("{direction} {distance} (#{distance_2:05x}{direction_2})\n")
+        })
+        .collect()
+}
@@ -1,8 +1,10 @@
+use std::collections::HashSet;
 use std::fmt::{Display, Formatter};
 
 use itertools::Itertools;
 
-use crate::day18::models::{DigPlan, Direction};
+use crate::geometry::Direction;
+use crate::year2023::day18::models::DigPlan;
 
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 enum LineType {
@@ -168,12 +170,11 @@ pub fn flood_dig_plan(dig_plan: &DigPlan) -> i64 {
     // iterate over instruction
     for (current_instruction, next_instruction) in dig_plan.instructions.iter().circular_tuple_windows() {
         // Compute end position
-        let end_position = match current_instruction.direction {
-            Direction::Up => (start_position.0 - current_instruction.distance, start_position.1),
-            Direction::Right => (start_position.0, start_position.1 + current_instruction.distance),
-            Direction::Down => (start_position.0 + current_instruction.distance, start_position.1),
-            Direction::Left => (start_position.0, start_position.1 - current_instruction.distance),
-        };
+        let (d_line, d_column) = current_instruction.direction.offset();
+        let end_position = (
+            start_position.0 + d_line as i64 * current_instruction.distance,
+            start_position.1 + d_column as i64 * current_instruction.distance,
+        );
 
         // Create the associated line
         lines.push(Line::new(
@@ -191,3 +192,42 @@ pub fn flood_dig_plan(dig_plan: &DigPlan) -> i64 {
 
     Lagoon { lines }.flood()
 }
+
+/// Reference implementation digging the trench tile by tile then flooding the outside of the lagoon
+pub fn flood_dig_plan_bruteforce(dig_plan: &DigPlan) -> i64 {
+    let mut position = (0, 0);
+    let mut trench = HashSet::from([position]);
+    for instruction in &dig_plan.instructions {
+        let (d_line, d_column) = instruction.direction.offset();
+        for _ in 0..instruction.distance {
+            position = (position.0 + d_line as i64, position.1 + d_column as i64);
+            trench.insert(position);
+        }
+    }
+
+    // Flood the outside of the trench from a corner of its bounding box, grown by one tile
+    let (min_line, max_line) = trench.iter().map(|tile| tile.0).minmax().into_option().unwrap();
+    let (min_column, max_column) = trench.iter().map(|tile| tile.1).minmax().into_option().unwrap();
+    let (min_line, max_line, min_column, max_column) = (min_line - 1, max_line + 1, min_column - 1, max_column + 1);
+
+    let mut outside = HashSet::from([(min_line, min_column)]);
+    let mut queue = vec![(min_line, min_column)];
+    while let Some((line, column)) = queue.pop() {
+        for neighbor in [
+            (line - 1, column),
+            (line, column + 1),
+            (line + 1, column),
+            (line, column - 1),
+        ] {
+            if (min_line..=max_line).contains(&neighbor.0)
+                && (min_column..=max_column).contains(&neighbor.1)
+                && !trench.contains(&neighbor)
+                && outside.insert(neighbor)
+            {
+                queue.push(neighbor);
+            }
+        }
+    }
+
+    (max_line - min_line + 1) * (max_column - min_column + 1) - outside.len() as i64
+}
@@ -0,0 +1,55 @@
+mod generator;
+mod logic;
+mod models;
+mod parser;
+
+#[cfg(test)]
+mod tests;
+
+use rand::rngs::StdRng;
+
+use crate::models::{AdventSolution, Answer};
+use crate::parser::ParseError;
+use crate::registry::Registration;
+use crate::year2023::day18::generator::generate_input;
+use crate::year2023::day18::logic::{flood_dig_plan, flood_dig_plan_bruteforce};
+use crate::year2023::day18::models::DigPlan;
+use crate::year2023::day18::parser::parse_input;
+
+pub const REGISTRATION: Registration = Registration::new::<Day18>(2023, 18, "Lavaduct Lagoon", [true, true]);
+
+#[derive(Default)]
+pub struct Day18 {
+    part_01: Option<DigPlan>,
+    part_02: Option<DigPlan>,
+}
+
+impl AdventSolution for Day18 {
+    fn parse(&mut self, _data: String) -> Result<(), ParseError> {
+        let dig_plans = parse_input(_data)?;
+        self.part_01 = Some(dig_plans.0);
+        self.part_02 = Some(dig_plans.1);
+        Ok(())
+    }
+
+    fn solve_part_one(&self) -> Answer {
+        flood_dig_plan(self.part_01.as_ref().unwrap()).into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        flood_dig_plan(self.part_02.as_ref().unwrap()).into()
+    }
+
+    fn solve_part_one_reference(&self) -> Option<Answer> {
+        Some(flood_dig_plan_bruteforce(self.part_01.as_ref().unwrap()).into())
+    }
+
+    fn generate_input(&self, size: usize, rng: &mut StdRng) -> Option<String> {
+        Some(generate_input(size, rng))
+    }
+
+    fn render(&self) -> Option<String> {
+        let (part_01, part_02) = (self.part_01.as_ref()?, self.part_02.as_ref()?);
+        Some(format!("Part one:\n{part_01}\nPart two:\n{part_02}"))
+    }
+}
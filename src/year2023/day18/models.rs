@@ -0,0 +1,33 @@
+use std::fmt::{Display, Formatter};
+
+use crate::geometry::Direction;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Instruction {
+    pub direction: Direction,
+    pub distance: i64,
+    // pub color: Color,
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.direction, self.distance)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct DigPlan {
+    pub instructions: Vec<Instruction>,
+}
+
+impl Display for DigPlan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Instructions:")?;
+
+        for instruction in &self.instructions {
+            writeln!(f, "{}", instruction)?;
+        }
+
+        Ok(())
+    }
+}
@@ -8,29 +8,18 @@ use nom::multi::many1;
 use nom::sequence::tuple;
 use nom::{IResult, Parser};
 
-use crate::day18::models::{DigPlan, Direction, Instruction};
+use crate::geometry::Direction;
+use crate::parser::{finish, ParseError};
+use crate::year2023::day18::models::{DigPlan, Instruction};
 
-impl From<char> for Direction {
-    fn from(value: char) -> Self {
-        match value {
-            'U' => Self::Up,
-            'R' => Self::Right,
-            'D' => Self::Down,
-            'L' => Self::Left,
-            _ => unreachable!(),
-        }
-    }
-}
-
-impl From<&str> for Direction {
-    fn from(value: &str) -> Self {
-        match value {
-            "3" => Self::Up,
-            "0" => Self::Right,
-            "1" => Self::Down,
-            "2" => Self::Left,
-            _ => unreachable!(),
-        }
+/// Direction encoded by the last digit of the color
+fn direction_from_digit(digit: char) -> Direction {
+    match digit {
+        '3' => Direction::Up,
+        '0' => Direction::Right,
+        '1' => Direction::Down,
+        '2' => Direction::Left,
+        _ => unreachable!(),
     }
 }
 
@@ -43,18 +32,18 @@ fn parse_instruction(input: &str) -> IResult<&str, (Instruction, Instruction)> {
             space1,
             tag("(#"),
             take(5usize),
-            take(1usize),
+            one_of("0123"),
             tag(")"),
             opt(line_ending),
         )),
         |(direction, _, distance, _, _, distance2, direction2, _, _)| {
             Ok::<_, ParseIntError>((
                 Instruction {
-                    direction: Direction::from(direction),
+                    direction: Direction::from_letter(direction).unwrap(),
                     distance: i64::from_str(distance)?,
                 },
                 Instruction {
-                    direction: Direction::from(direction2),
+                    direction: direction_from_digit(direction2),
                     distance: i64::from_str_radix(distance2, 16)?,
                 },
             ))
@@ -78,11 +67,6 @@ fn parse_dig_plan(input: &str) -> IResult<&str, (DigPlan, DigPlan)> {
     .parse(input)
 }
 
-pub fn parse_input(input: String) -> (DigPlan, DigPlan) {
-    let (res, dig_plans) = parse_dig_plan(&input).unwrap();
-    if !res.is_empty() {
-        panic!("Unable to fully parse the input: {}", res);
-    }
-
-    dig_plans
+pub fn parse_input(input: String) -> Result<(DigPlan, DigPlan), ParseError> {
+    finish(18, &input, parse_dig_plan(&input))
 }
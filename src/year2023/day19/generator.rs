@@ -0,0 +1,62 @@
+use itertools::Itertools;
+use rand::Rng;
+
+/// Create a unique lowercase workflow name from an index
+fn workflow_name(index: usize) -> String {
+    if index == 0 {
+        return "in".to_string();
+    }
+
+    let mut name = String::new();
+    let mut index = index;
+    while index > 0 || name.len() < 2 {
+        name.push(char::from(b'a' + (index % 26) as u8));
+        index /= 26;
+    }
+    // Avoid a name colliding with the first workflow
+    if name == "in" {
+        name.push('x');
+    }
+    name
+}
+
+/// Pick the action of a rule of the workflow `index`, only workflows defined after it can be targeted
+fn action(index: usize, size: usize, rng: &mut impl Rng) -> String {
+    match rng.gen_range(index + 1..=size + 1) {
+        target if target == size => "A".to_string(),
+        target if target > size => "R".to_string(),
+        target => workflow_name(target),
+    }
+}
+
+/// Generate `size` workflows and `size` parts.
+/// Workflows only send parts to workflows defined after them so that every part is eventually accepted or rejected.
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    let size = size.max(1);
+
+    let workflows: String = (0..size)
+        .map(|index| {
+            let rules = (0..rng.gen_range(1..=4))
+                .map(|_| {
+                    format!(
+                        "{}{}{}:{}",
+                        ['x', 'm', 'a', 's'][rng.gen_range(0..4)],
+                        ['<', '>'][rng.gen_range(0..2)],
+                        rng.gen_range(1..4000),
+                        action(index, size, rng)
+                    )
+                })
+                .join(",");
+            format!("{}{{{rules},{}}}\n", workflow_name(index), action(index, size, rng))
+        })
+        .collect();
+
+    let parts: String = (0..size)
+        .map(|_| {
+            let [x, m, a, s] = [(); 4].map(|_| rng.gen_range(1..=4000));
+            format!("{{x={x},m={m},a={a},s={s}}}\n")
+        })
+        .collect();
+
+    format!("{workflows}\n{parts}")
+}
@@ -0,0 +1,150 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::interval::{Interval, IntervalBox};
+use crate::year2023::day19::models::{Action, Part, PartValue, Rule, System, Test, Workflow};
+
+impl Part {
+    fn value(&self, part_value: &PartValue) -> i64 {
+        match part_value {
+            PartValue::X => self.x,
+            PartValue::M => self.m,
+            PartValue::A => self.a,
+            PartValue::S => self.s,
+        }
+    }
+}
+
+impl Rule {
+    fn matches(&self, part: &Part) -> bool {
+        let value = part.value(&self.part_value);
+        match self.test {
+            Test::Lower => value < self.value,
+            Test::Greater => value > self.value,
+        }
+    }
+
+    fn get_action(&self, part: &Part) -> Option<&Action> {
+        if self.matches(part) {
+            Some(&self.action)
+        } else {
+            None
+        }
+    }
+}
+
+impl Workflow {
+    fn get_action(&self, part: &Part) -> &Action {
+        for rule in &self.rules {
+            if let Some(action) = rule.get_action(part) {
+                return action;
+            }
+        }
+
+        &self.default_action
+    }
+}
+
+fn is_accepted(part: &Part, workflows: &HashMap<String, Workflow>) -> bool {
+    // Get the first workflow
+    let mut workflow_name: &str = "in";
+
+    loop {
+        let workflow = &workflows[workflow_name];
+        let action = workflow.get_action(part);
+
+        match action {
+            Action::Goto(target) => workflow_name = target.as_str(),
+            Action::Accepted => return true,
+            Action::Rejected => return false,
+        }
+    }
+}
+
+pub fn solve_part_one(system: &System) -> i64 {
+    system
+        .parts
+        .iter()
+        .filter(|part| is_accepted(part, &system.workflows))
+        .map(|part| part.x + part.m + part.a + part.s)
+        .sum()
+}
+
+/// Ranges of the x, m, a and s values of the parts, in this order
+type PartRange = IntervalBox<4>;
+
+impl PartValue {
+    /// Dimension of the value in a `PartRange`
+    fn dimension(&self) -> usize {
+        match self {
+            PartValue::X => 0,
+            PartValue::M => 1,
+            PartValue::A => 2,
+            PartValue::S => 3,
+        }
+    }
+}
+
+pub fn solve_part_two(system: &System) -> i64 {
+    // Create structures
+    let mut res = 0;
+    let mut queue: VecDeque<(&str, PartRange)> = VecDeque::new();
+
+    // Add the initial PartRange
+    queue.push_back(("in", PartRange::new([Interval::new(1, 4001); 4])));
+
+    // While we still have item in the queue
+    while let Some((workflow_name, part_range)) = queue.pop_front() {
+        let workflow = &system.workflows[workflow_name];
+
+        let mut part_range = Some(part_range);
+
+        for rule in &workflow.rules {
+            if let Some(inner_part_range) = part_range.as_ref() {
+                // Split the range of the tested value accordingly
+                let dimension = rule.part_value.dimension();
+                let (matched, remaining) = match rule.test {
+                    Test::Lower => inner_part_range.split_at(dimension, rule.value),
+                    Test::Greater => {
+                        let (remaining, matched) = inner_part_range.split_at(dimension, rule.value + 1);
+                        (matched, remaining)
+                    }
+                };
+
+                // If the matched range is not null
+                if let Some(matched_part_range) = matched {
+                    // Do the action according to the action
+                    match &rule.action {
+                        Action::Goto(x) => {
+                            // Add the new part range to the queue to run it in another workflow
+                            queue.push_back((x.as_str(), matched_part_range));
+                        }
+                        Action::Accepted => {
+                            // Update the result
+                            res += matched_part_range.volume();
+                        }
+                        Action::Rejected => {
+                            // Eject the part from the system by doing nothing
+                        }
+                    }
+                }
+
+                // Update part_range
+                part_range = remaining;
+            } else {
+                // We don't have any part_range left to match
+                break;
+            }
+        }
+
+        // If part_range is not null, apply the default option
+        if let Some(inner_part_range) = part_range {
+            match &workflow.default_action {
+                Action::Goto(x) => queue.push_back((x.as_str(), inner_part_range)),
+                Action::Accepted => res += inner_part_range.volume(),
+                Action::Rejected => {}
+            }
+        }
+    }
+
+    res
+}
@@ -38,9 +38,6 @@ pub struct Rule {
 
     // Action
     pub action: Action,
-
-    // Helper func
-    pub test_func: fn(&Part, i64) -> bool,
 }
 
 #[derive(Debug, Eq, PartialEq, Clone)]
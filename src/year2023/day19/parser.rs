@@ -0,0 +1,152 @@
+use std::collections::HashSet;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use nom::bytes::complete::tag;
+use nom::character::complete::{alpha1, digit1, line_ending, one_of};
+use nom::combinator::{map, map_res, opt};
+use nom::multi::{many1, separated_list0};
+use nom::sequence::tuple;
+use nom::{IResult, Parser};
+
+use crate::parser::{finish, ParseError};
+use crate::year2023::day19::models::{Action, Part, PartValue, Rule, System, Test, Workflow};
+
+impl From<char> for PartValue {
+    fn from(value: char) -> Self {
+        match value {
+            'x' => PartValue::X,
+            'm' => PartValue::M,
+            'a' => PartValue::A,
+            's' => PartValue::S,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl From<char> for Test {
+    fn from(value: char) -> Self {
+        match value {
+            '<' => Test::Lower,
+            '>' => Test::Greater,
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Parse the action of a rule, along with the name it was parsed from to locate the workflows it refers to
+fn parse_action(input: &str) -> IResult<&str, (&str, Action)> {
+    map(alpha1, |name| {
+        let action = match name {
+            "A" => Action::Accepted,
+            "R" => Action::Rejected,
+            s => Action::Goto(s.to_string()),
+        };
+        (name, action)
+    })
+    .parse(input)
+}
+
+fn parse_rule(input: &str) -> IResult<&str, (&str, Rule)> {
+    map_res(
+        tuple((one_of("xmas"), one_of("<>"), digit1, tag(":"), parse_action)),
+        |(part_value, test, value, _, (target, action))| {
+            let value = i64::from_str(value)?;
+            Ok::<_, ParseIntError>((
+                target,
+                Rule {
+                    part_value: PartValue::from(part_value),
+                    test: Test::from(test),
+                    value,
+                    action,
+                },
+            ))
+        },
+    )
+    .parse(input)
+}
+
+/// Parse a workflow, along with the names of its actions
+fn parse_workflow(input: &str) -> IResult<&str, (&str, Workflow, Vec<&str>)> {
+    map(
+        tuple((
+            alpha1,
+            tag("{"),
+            separated_list0(tag(","), parse_rule),
+            tag(","),
+            parse_action,
+            tag("}"),
+            opt(line_ending),
+        )),
+        |(name, _, rules, _, (default_target, default_action), _, _)| {
+            let (mut targets, rules): (Vec<_>, Vec<_>) = rules.into_iter().unzip();
+            targets.push(default_target);
+
+            (
+                name,
+                Workflow {
+                    name: name.to_string(),
+                    rules,
+                    default_action,
+                },
+                targets,
+            )
+        },
+    )
+    .parse(input)
+}
+
+fn parse_part(input: &str) -> IResult<&str, Part> {
+    map_res(
+        tuple((
+            tag("{x="),
+            digit1,
+            tag(",m="),
+            digit1,
+            tag(",a="),
+            digit1,
+            tag(",s="),
+            digit1,
+            tag("}"),
+            opt(line_ending),
+        )),
+        |(_, x, _, m, _, a, _, s, _, _)| {
+            Ok::<_, ParseIntError>(Part {
+                x: i64::from_str(x)?,
+                m: i64::from_str(m)?,
+                a: i64::from_str(a)?,
+                s: i64::from_str(s)?,
+            })
+        },
+    )
+    .parse(input)
+}
+
+pub fn parse_input(input: String) -> Result<System, ParseError> {
+    let (workflows, _, parts) = finish(
+        19,
+        &input,
+        tuple((many1(parse_workflow), many1(line_ending), many1(parse_part))).parse(&input),
+    )?;
+
+    // Every workflow an action goes to must be defined, and the parts enter the system through `in`
+    let names: HashSet<_> = workflows.iter().map(|(name, _, _)| *name).collect();
+    let targets = workflows.iter().flat_map(|(_, _, targets)| targets);
+    if let Some(target) = targets
+        .filter(|target| !matches!(**target, "A" | "R"))
+        .find(|target| !names.contains(*target))
+    {
+        return Err(ParseError::at(19, &input, target, "the name of a defined workflow"));
+    }
+    if !names.contains("in") {
+        return Err(ParseError::new(19, &input, 0, "a workflow named `in`"));
+    }
+
+    Ok(System {
+        workflows: workflows
+            .into_iter()
+            .map(|(name, workflow, _)| (name.to_string(), workflow))
+            .collect(),
+        parts,
+    })
+}
@@ -0,0 +1,39 @@
+use crate::common::{test_examples_common, test_generated_input_common};
+use crate::parser::ParseError;
+use crate::year2023::day19::parser::parse_input;
+use crate::year2023::day19::{Day19, REGISTRATION};
+
+#[test]
+fn test_parse_undefined_workflow() {
+    assert_eq!(
+        parse_input("in{x<10:px,R}\npx{a>5:qq,A}\n\n{x=1,m=2,a=3,s=4}\n".to_string()),
+        Err(ParseError {
+            day: 19,
+            line: 2,
+            column: 8,
+            expected: "the name of a defined workflow".to_string(),
+            snippet: "px{a>5:qq,A}".to_string(),
+        })
+    );
+    assert_eq!(
+        parse_input("px{a>5:A,R}\n\n{x=1,m=2,a=3,s=4}\n".to_string()),
+        Err(ParseError {
+            day: 19,
+            line: 1,
+            column: 1,
+            expected: "a workflow named `in`".to_string(),
+            snippet: "px{a>5:A,R}".to_string(),
+        })
+    );
+    assert!(parse_input("in{x<10:px,R}\npx{a>5:A,in}\n\n{x=1,m=2,a=3,s=4}\n".to_string()).is_ok());
+}
+
+#[test]
+fn test_examples() {
+    test_examples_common(&REGISTRATION);
+}
+
+#[test]
+fn test_generated_input() {
+    test_generated_input_common::<Day19>();
+}
@@ -0,0 +1,42 @@
+use rand::Rng;
+
+/// Generate a module network shaped like the real inputs: the broadcaster starts `size` binary counters, at most 15,
+/// of 12 flip-flops, every counter resets through a conjunction when it reaches a random value,
+/// and the conjunctions are combined through inverters into a final conjunction sending pulses to `rx`.
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    let mut modules = vec![];
+    let mut broadcaster_outputs = vec![];
+
+    for counter in 0..size.clamp(1, 15) {
+        let letter = char::from(b'a' + counter as u8);
+        let flip_flop = |bit: usize| format!("f{letter}{}", char::from(b'a' + bit as u8));
+        let conjunction = format!("c{letter}");
+        let inverter = format!("i{letter}");
+
+        // The counter resets when reaching this odd 12 bits value
+        let period: u32 = rng.gen_range(0x800..0x1000) | 1;
+        let mut conjunction_outputs = vec![inverter.clone()];
+        for bit in 0..12 {
+            let mut outputs = vec![];
+            if bit < 11 {
+                outputs.push(flip_flop(bit + 1));
+            }
+            if period & (1 << bit) != 0 {
+                outputs.push(conjunction.clone());
+            }
+            if bit == 0 || period & (1 << bit) == 0 {
+                conjunction_outputs.push(flip_flop(bit));
+            }
+            modules.push(format!("%{} -> {}\n", flip_flop(bit), outputs.join(", ")));
+        }
+        modules.push(format!("&{conjunction} -> {}\n", conjunction_outputs.join(", ")));
+        modules.push(format!("&{inverter} -> zz\n"));
+        broadcaster_outputs.push(flip_flop(0));
+    }
+
+    format!(
+        "broadcaster -> {}\n{}&zz -> rx\n",
+        broadcaster_outputs.join(", "),
+        modules.concat()
+    )
+}
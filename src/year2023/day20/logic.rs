@@ -3,7 +3,9 @@ use std::collections::VecDeque;
 // use petgraph::dot::{Config, Dot};
 use petgraph::Graph;
 
-use crate::day20::models::{Broadcaster, CableManagement, Conjunction, FlipFlop, Module, ModuleType, Untyped};
+use crate::year2023::day20::models::{
+    Broadcaster, CableManagement, Conjunction, FlipFlop, Module, ModuleType, Untyped,
+};
 
 impl Module for Untyped {
     fn get_pulses(&mut self, _input_offset: u16, _is_high: bool) -> Option<(&Vec<(usize, u16)>, bool)> {
@@ -8,7 +8,10 @@ use nom::multi::{many1, separated_list1};
 use nom::sequence::tuple;
 use nom::{IResult, Parser};
 
-use crate::day20::models::{Broadcaster, CableManagement, Conjunction, FlipFlop, Module, ModuleType, Untyped};
+use crate::parser::{finish, ParseError};
+use crate::year2023::day20::models::{
+    Broadcaster, CableManagement, Conjunction, FlipFlop, Module, ModuleType, Untyped,
+};
 
 impl From<&str> for ModuleType {
     fn from(value: &str) -> Self {
@@ -52,8 +55,8 @@ fn build_modules<'a>(mut modules_data: Vec<(ModuleType, &'a str, Vec<&'a str>)>)
             // Update the input count
             *modules_input_count.entry(*output).or_default() += 1;
 
-            // Add it to the untyped output list if necessary
-            if modules_positions.get(*output).is_none() {
+            // Add it to the untyped output list if necessary, once even if several modules output to it
+            if !modules_positions.contains_key(*output) && !untyped_outputs.contains(output) {
                 untyped_outputs.push(*output);
             }
         }
@@ -86,7 +89,11 @@ fn build_modules<'a>(mut modules_data: Vec<(ModuleType, &'a str, Vec<&'a str>)>)
         modules.push(match module_type {
             ModuleType::Broadcaster => Box::new(Broadcaster::new(output)),
             ModuleType::FlipFlop => Box::new(FlipFlop::new(name.to_string(), output)),
-            ModuleType::Conjunction => Box::new(Conjunction::new(name.to_string(), output, modules_input_count[name])),
+            ModuleType::Conjunction => {
+                // A conjunction without input is never triggered
+                let input_count = modules_input_count.get(name).copied().unwrap_or(0);
+                Box::new(Conjunction::new(name.to_string(), output, input_count))
+            }
             ModuleType::Untyped => Box::new(Untyped::new(name.to_string())),
         });
     }
@@ -94,18 +101,11 @@ fn build_modules<'a>(mut modules_data: Vec<(ModuleType, &'a str, Vec<&'a str>)>)
     modules
 }
 
-fn parse_cable_management(input: &str) -> IResult<&str, CableManagement> {
-    map(many1(parse_module), |modules| CableManagement {
-        modules: build_modules(modules),
-    })
-    .parse(input)
-}
+pub fn parse_input(input: String) -> Result<CableManagement, ParseError> {
+    // The modules are only built once the whole input is parsed, as they reference each other
+    let modules_data = finish(20, &input, many1(parse_module).parse(&input))?;
 
-pub fn parse_input(input: String) -> CableManagement {
-    let (res, cable_management) = parse_cable_management(&input).unwrap();
-    if !res.is_empty() {
-        panic!("Unable to fully parse the input: {}", res);
-    }
-
-    cable_management
+    Ok(CableManagement {
+        modules: build_modules(modules_data),
+    })
 }
@@ -0,0 +1,40 @@
+use crate::common::{test_examples_common, test_generated_input_common};
+use crate::parser::ParseError;
+use crate::year2023::day20::parser::parse_input;
+use crate::year2023::day20::{Day20, REGISTRATION};
+
+#[test]
+fn test_parse_unconnected_modules() {
+    // A conjunction may have no input, and an untyped module is only added once whatever its input count
+    let cable_management = parse_input("broadcaster -> a, c\n&b -> a\n%c -> a\n".to_string()).unwrap();
+    let names: Vec<_> = cable_management
+        .modules
+        .iter()
+        .map(|module| module.get_name())
+        .collect();
+    assert_eq!(names, vec!["broadcaster", "b", "c", "a"]);
+}
+
+#[test]
+fn test_parse_truncated_input() {
+    assert_eq!(
+        parse_input("broadcaster -> a\n&b -> ".to_string()).err(),
+        Some(ParseError {
+            day: 20,
+            line: 2,
+            column: 1,
+            expected: "the end of the input".to_string(),
+            snippet: "&b -> ".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_examples() {
+    test_examples_common(&REGISTRATION);
+}
+
+#[test]
+fn test_generated_input() {
+    test_generated_input_common::<Day20>();
+}
@@ -0,0 +1,32 @@
+use rand::Rng;
+
+/// Generate a garden of about `size` x `size` tiles (the size is made odd) with the start at its center.
+/// The borders and the middle line and column are kept empty, as part two expects.
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    let size = size.max(5) | 1;
+    let middle = size / 2;
+
+    (0..size)
+        .map(|line| {
+            (0..size)
+                .map(|column| {
+                    if line == middle && column == middle {
+                        'S'
+                    } else if line == 0
+                        || line == middle
+                        || line == size - 1
+                        || column == 0
+                        || column == middle
+                        || column == size - 1
+                        || !rng.gen_bool(0.15)
+                    {
+                        '.'
+                    } else {
+                        '#'
+                    }
+                })
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
@@ -0,0 +1,282 @@
+use std::collections::VecDeque;
+
+use bucket_queue::{BucketQueue, FirstInFirstOutQueue};
+
+use crate::assumptions::Assumption;
+use crate::grid::Grid;
+use crate::year2023::day21::models::Map;
+
+fn count_accessible_tiles(map: &Map, start: (usize, usize), steps: usize, parity: usize) -> usize {
+    if steps == 0 {
+        return 0;
+    }
+
+    // Use the Dijkstra in order to determinate the distance we can travel in steps steps.
+
+    // Create structures to support the algorithm
+    let mut visited = Grid::filled(map.grid.width(), map.grid.height(), false);
+    let mut scores = Grid::filled(map.grid.width(), map.grid.height(), usize::MAX);
+    let mut visit_next = BucketQueue::<VecDeque<_>>::new();
+
+    // Add the start node
+    visit_next.enqueue((0, start, steps), 0);
+
+    // Main loop
+    while let Some((score, position, remaining_steps)) = visit_next.dequeue_min() {
+        // If the node was already visited, we don't have to do it again
+        // If we don't have any more steps to use, skip it
+        if visited[position] || remaining_steps == 0 {
+            continue;
+        }
+
+        for neighbor in map.grid.neighbors4(position) {
+            if !map.grid[neighbor] && score + 1 < scores[neighbor] {
+                scores[neighbor] = score + 1;
+                visit_next.enqueue((score + 1, neighbor, remaining_steps - 1), score + 1);
+            }
+        }
+
+        visited[position] = true;
+    }
+
+    // The result is equal to the number of distance that has the same remainder as steps by 2 as steps.
+    scores
+        .cells()
+        .iter()
+        .filter(|score| **score != usize::MAX && **score % 2 == parity)
+        .count()
+}
+
+pub fn solve_part_one(map: &Map, steps: usize) -> usize {
+    count_accessible_tiles(map, map.start, steps, 0)
+}
+
+/// Reference implementation of part two counting the tiles on enough copies of the map
+pub fn solve_part_two_bruteforce(map: &Map, steps: usize) -> usize {
+    let (width, height) = (map.grid.width(), map.grid.height());
+
+    // Compute how many maps we will need for the bruteforce
+    let map_count = 2 * (steps / width) + 1;
+
+    // Build a map that is a concatenation of every map
+    let grid = Grid::from_fn(width * map_count, height * map_count, |(line, column)| {
+        map.grid[(line % height, column % width)]
+    });
+
+    let start = (
+        map_count / 2 * height + map.start.0,
+        map_count / 2 * width + map.start.1,
+    );
+    let new_map = Map::new(grid, start);
+
+    count_accessible_tiles(&new_map, start, steps, steps % 2)
+}
+
+/// Properties of the map and of the number of steps that part two relies on
+pub fn check_assumptions(map: &Map, steps: usize) -> Vec<Assumption> {
+    let (width, height) = (map.grid.width(), map.grid.height());
+    let rocks_on_line = |line: usize| map.grid.line(line).iter().filter(|rock| **rock).count();
+    let rocks_on_column = |column: usize| map.grid.column(column).filter(|rock| **rock).count();
+
+    let mut assumptions = vec![
+        Assumption::check("square", "The map must be a square", width == height, || {
+            format!("the map is {} wide and {} high", width, height)
+        }),
+        Assumption::check("odd_width", "The map width must be odd", width % 2 != 0, || {
+            format!("the map is {} wide", width)
+        }),
+        Assumption::check(
+            "centered_start",
+            "The start must be at the center of the map",
+            map.start == (height / 2, width / 2),
+            || {
+                format!(
+                    "the start is at line {}, column {} instead of line {}, column {}",
+                    map.start.0 + 1,
+                    map.start.1 + 1,
+                    height / 2 + 1,
+                    width / 2 + 1
+                )
+            },
+        ),
+    ];
+    for (name, description, line) in [
+        ("empty_first_line", "The first line must be empty", 0),
+        ("empty_middle_line", "The middle line must be empty", height / 2),
+        ("empty_last_line", "The last line must be empty", height - 1),
+    ] {
+        let rocks = rocks_on_line(line);
+        assumptions.push(Assumption::check(name, description, rocks == 0, || {
+            format!("line {} contains {rocks} rocks", line + 1)
+        }));
+    }
+    for (name, description, column) in [
+        ("empty_first_column", "The first column must be empty", 0),
+        ("empty_middle_column", "The middle column must be empty", width / 2),
+        ("empty_last_column", "The last column must be empty", width - 1),
+    ] {
+        let rocks = rocks_on_column(column);
+        assumptions.push(Assumption::check(name, description, rocks == 0, || {
+            format!("column {} contains {rocks} rocks", column + 1)
+        }));
+    }
+
+    // The last step reaches the middle of the border of a copy of the map, at least one full map away from the start
+    assumptions.push(Assumption::check(
+        "steps_end_on_border",
+        "The steps of part two must end on the border of a copy of the map",
+        steps >= width && steps % width == width / 2,
+        || {
+            format!(
+                "{steps} steps do not have the form k * {width} + {} with k at least 1",
+                width / 2
+            )
+        },
+    ));
+
+    assumptions
+}
+
+pub fn solve_part_two(map: &Map, steps: usize) -> usize {
+    let parity = steps % 2;
+    let (width, height) = (map.grid.width(), map.grid.height());
+
+    // Verify assertions
+    if let Some(assumption) = check_assumptions(map, steps)
+        .into_iter()
+        .find(|assumption| !assumption.holds())
+    {
+        panic!("{}: {}", assumption.description, assumption.failure.unwrap())
+    }
+
+    // Compute the number of full square that we will get on the main line / column
+    let main_line_length = steps / width;
+
+    // Compute the number of positions for a full map
+    let full_map_positions_count_base_par = count_accessible_tiles(map, map.start, width, parity);
+    let full_map_positions_count_other_par = count_accessible_tiles(map, map.start, width, (parity + 1) % 2);
+
+    // Compute how many steps will be left on the left map
+    let offset_at_line_end = steps - main_line_length * width + width / 2;
+
+    // Compute the number of position for the left, top, right and bottom map.
+    // They are entered in the middle of a side, width / 2 + 1 steps away from the start of the previous map.
+    let left_map_position_count = count_accessible_tiles(
+        map,
+        (map.start.0, width - 1),
+        offset_at_line_end,
+        (parity + main_line_length + width / 2) % 2,
+    ) + count_accessible_tiles(
+        map,
+        (map.start.0, width - 1),
+        offset_at_line_end.saturating_sub(width),
+        (parity + main_line_length + width / 2 + 1) % 2,
+    );
+    let top_map_position_count = count_accessible_tiles(
+        map,
+        (height - 1, map.start.1),
+        offset_at_line_end,
+        (parity + main_line_length + width / 2) % 2,
+    ) + count_accessible_tiles(
+        map,
+        (height - 1, map.start.1),
+        offset_at_line_end.saturating_sub(width),
+        (parity + main_line_length + width / 2 + 1) % 2,
+    );
+    let right_map_position_count = count_accessible_tiles(
+        map,
+        (map.start.0, 0),
+        offset_at_line_end,
+        (parity + main_line_length + width / 2) % 2,
+    ) + count_accessible_tiles(
+        map,
+        (map.start.0, 0),
+        offset_at_line_end.saturating_sub(width),
+        (parity + main_line_length + width / 2 + 1) % 2,
+    );
+    let bottom_map_position_count = count_accessible_tiles(
+        map,
+        (0, map.start.1),
+        offset_at_line_end,
+        (parity + main_line_length + width / 2) % 2,
+    ) + count_accessible_tiles(
+        map,
+        (0, map.start.1),
+        offset_at_line_end.saturating_sub(width),
+        (parity + main_line_length + width / 2 + 1) % 2,
+    );
+
+    // Compute how many will be left on the diagonal corners
+    let offset_at_diagonal_end = steps - (main_line_length - 1) * width - 1;
+
+    // Compute diagonal res
+    let upper_left_map_position_count_1 = count_accessible_tiles(
+        map,
+        (height - 1, height - 1),
+        offset_at_diagonal_end,
+        (parity + main_line_length) % 2,
+    );
+    let upper_left_map_position_count_2 = count_accessible_tiles(
+        map,
+        (height - 1, height - 1),
+        offset_at_diagonal_end.saturating_sub(width),
+        (parity + main_line_length + 1) % 2,
+    );
+    let upper_right_map_position_count_1 = count_accessible_tiles(
+        map,
+        (height - 1, 0),
+        offset_at_diagonal_end,
+        (parity + main_line_length) % 2,
+    );
+    let upper_right_map_position_count_2 = count_accessible_tiles(
+        map,
+        (height - 1, 0),
+        offset_at_diagonal_end.saturating_sub(width),
+        (parity + main_line_length + 1) % 2,
+    );
+    let lower_right_map_position_count_1 =
+        count_accessible_tiles(map, (0, 0), offset_at_diagonal_end, (parity + main_line_length) % 2);
+    let lower_right_map_position_count_2 = count_accessible_tiles(
+        map,
+        (0, 0),
+        offset_at_diagonal_end.saturating_sub(width),
+        (parity + main_line_length + 1) % 2,
+    );
+    let lower_left_map_position_count_1 = count_accessible_tiles(
+        map,
+        (0, height - 1),
+        offset_at_diagonal_end,
+        (parity + main_line_length) % 2,
+    );
+    let lower_left_map_position_count_2 = count_accessible_tiles(
+        map,
+        (0, height - 1),
+        offset_at_diagonal_end.saturating_sub(width),
+        (parity + main_line_length + 1) % 2,
+    );
+
+    // Compute how many full grid we have
+    let full_grid_count_base_par = (main_line_length - (main_line_length - 1) % 2).pow(2);
+    let full_grid_count_other_par = (main_line_length - (main_line_length) % 2).pow(2);
+    let inner_diagonal_count = main_line_length - 1;
+    let out_diagonal_count = main_line_length;
+
+    // Sum it up
+
+    full_map_positions_count_base_par * full_grid_count_base_par
+        + full_map_positions_count_other_par * full_grid_count_other_par
+        + left_map_position_count
+        + right_map_position_count
+        + top_map_position_count
+        + bottom_map_position_count
+        + inner_diagonal_count
+            * (upper_left_map_position_count_1
+                + upper_right_map_position_count_1
+                + lower_left_map_position_count_1
+                + lower_right_map_position_count_1)
+        + out_diagonal_count
+            * (upper_left_map_position_count_2
+                + upper_right_map_position_count_2
+                + lower_left_map_position_count_2
+                + lower_right_map_position_count_2)
+}
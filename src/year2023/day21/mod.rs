@@ -0,0 +1,94 @@
+mod generator;
+mod logic;
+mod models;
+mod parser;
+
+#[cfg(test)]
+mod tests;
+
+use rand::rngs::StdRng;
+
+use crate::assumptions::Assumption;
+use crate::models::{AdventSolution, Answer};
+use crate::parameters::{Parameter, ParameterError, ParameterValue};
+use crate::parser::ParseError;
+use crate::registry::Registration;
+use crate::year2023::day21::generator::generate_input;
+use crate::year2023::day21::logic::{check_assumptions, solve_part_one, solve_part_two, solve_part_two_bruteforce};
+use crate::year2023::day21::models::Map;
+use crate::year2023::day21::parser::parse_input;
+
+pub const REGISTRATION: Registration = Registration::new::<Day21>(2023, 21, "Step Counter", [true, true]);
+
+pub struct Day21 {
+    part_one_steps: usize,
+    part_two_steps: usize,
+    parsed_data: Option<Map>,
+}
+
+impl Default for Day21 {
+    fn default() -> Self {
+        Self {
+            part_one_steps: 64,
+            part_two_steps: 26501365,
+            parsed_data: None,
+        }
+    }
+}
+
+impl AdventSolution for Day21 {
+    fn parse(&mut self, _data: String) -> Result<(), ParseError> {
+        self.parsed_data = Some(parse_input(_data)?);
+        Ok(())
+    }
+
+    fn solve_part_one(&self) -> Answer {
+        solve_part_one(self.parsed_data.as_ref().unwrap(), self.part_one_steps).into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        solve_part_two(self.parsed_data.as_ref().unwrap(), self.part_two_steps).into()
+    }
+
+    fn solve_part_two_reference(&self) -> Option<Answer> {
+        Some(solve_part_two_bruteforce(self.parsed_data.as_ref().unwrap(), self.part_two_steps).into())
+    }
+
+    fn check_assumptions(&self) -> Vec<Assumption> {
+        check_assumptions(self.parsed_data.as_ref().unwrap(), self.part_two_steps)
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        let default = Self::default();
+        vec![
+            Parameter::new(
+                "part_one_steps",
+                "Number of steps of part one",
+                ParameterValue::Unsigned(default.part_one_steps),
+            ),
+            Parameter::new(
+                "part_two_steps",
+                "Number of steps of part two, k * width + width / 2 for a map of the given width and k at least 1",
+                ParameterValue::Unsigned(default.part_two_steps),
+            ),
+        ]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: ParameterValue) -> Result<(), ParameterError> {
+        match (name, value) {
+            ("part_one_steps", ParameterValue::Unsigned(value)) => self.part_one_steps = value,
+            ("part_two_steps", ParameterValue::Unsigned(value)) => self.part_two_steps = value,
+            _ => unreachable!(),
+        }
+
+        Ok(())
+    }
+
+    fn generate_input(&self, size: usize, rng: &mut StdRng) -> Option<String> {
+        Some(generate_input(size, rng))
+    }
+
+    fn render(&self) -> Option<String> {
+        self.parsed_data.as_ref().map(|data| data.to_string())
+    }
+}
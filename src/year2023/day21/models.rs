@@ -0,0 +1,31 @@
+use std::fmt::{Display, Formatter};
+
+use crate::grid::Grid;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Map {
+    pub grid: Grid<bool>, // true if their is a rock, false if clear
+    pub start: (usize, usize),
+}
+
+impl Map {
+    pub fn new(grid: Grid<bool>, start: (usize, usize)) -> Self {
+        Self { grid, start }
+    }
+}
+
+impl Display for Map {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let rendered = self.grid.render(|position, rock| {
+            if position == self.start {
+                "S".to_string()
+            } else if *rock {
+                "#".to_string()
+            } else {
+                ".".to_string()
+            }
+        });
+
+        write!(f, "{rendered}Start!: {:?}", &self.start)
+    }
+}
@@ -0,0 +1,19 @@
+use crate::grid::Grid;
+use crate::parser::{check_charset, check_rectangular, ParseError};
+use crate::year2023::day21::models::Map;
+
+pub fn parse_input(input: String) -> Result<Map, ParseError> {
+    check_charset(21, &input, ".#S")?;
+    check_rectangular(21, &input)?;
+
+    let grid = Grid::parse_with(&input, |tile| tile == '#');
+
+    let start = input
+        .lines()
+        .flat_map(|line| line.chars())
+        .position(|tile| tile == 'S')
+        .map(|offset| grid.position(offset))
+        .ok_or_else(|| ParseError::new(21, &input, 0, "a starting tile 'S'"))?;
+
+    Ok(Map::new(grid, start))
+}
@@ -0,0 +1,88 @@
+use crate::common::{test_against_reference_common, test_examples_common, test_generated_input_common};
+use crate::models::AdventSolution;
+use crate::parser::ParseError;
+use crate::runner::catch_panic;
+use crate::year2023::day21::parser::parse_input;
+use crate::year2023::day21::{Day21, REGISTRATION};
+
+static INPUT_EXAMPLE: &str = include_str!("../../../input_examples/2023/day21_2");
+
+static INPUT_EXAMPLE_2: &str = include_str!("../../../input_examples/2023/day21");
+
+#[test]
+fn test_parse_missing_start() {
+    assert_eq!(
+        parse_input("...\n.#.\n...\n".to_string()).err(),
+        Some(ParseError {
+            day: 21,
+            line: 1,
+            column: 1,
+            expected: "a starting tile 'S'".to_string(),
+            snippet: "...".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_examples() {
+    test_examples_common(&REGISTRATION);
+}
+
+fn failures(solution: &Day21) -> Vec<&'static str> {
+    solution
+        .check_assumptions()
+        .into_iter()
+        .filter(|assumption| !assumption.holds())
+        .map(|assumption| assumption.name)
+        .collect()
+}
+
+#[test]
+fn test_assumptions() {
+    let mut solution = Day21::default();
+    solution.configure("part_two_steps", "49").unwrap();
+    solution.parse(INPUT_EXAMPLE.to_string()).unwrap();
+    assert_eq!(failures(&solution), Vec::<&str>::new());
+
+    let mut solution = Day21::default();
+    solution.configure("part_two_steps", "49").unwrap();
+    solution.parse(INPUT_EXAMPLE_2.to_string()).unwrap();
+    assert_eq!(failures(&solution), vec!["empty_middle_line", "empty_middle_column"]);
+}
+
+#[test]
+fn test_assumptions_steps() {
+    // Part two only works when the last step reaches the border of a copy of the map, at least one map away
+    let mut solution = Day21::default();
+    solution.parse(INPUT_EXAMPLE.to_string()).unwrap();
+    for (steps, holds) in [(5, false), (16, true), (26501365, false), (26501370, true)] {
+        solution.configure("part_two_steps", &steps.to_string()).unwrap();
+        assert_eq!(failures(&solution).is_empty(), holds, "{steps} steps");
+    }
+
+    solution.configure("part_two_steps", "20").unwrap();
+    let assumption = solution
+        .check_assumptions()
+        .into_iter()
+        .find(|assumption| assumption.name == "steps_end_on_border")
+        .unwrap();
+    assert_eq!(
+        assumption.failure,
+        Some("20 steps do not have the form k * 11 + 5 with k at least 1".to_string())
+    );
+    assert!(catch_panic(|| solution.solve_part_two()).is_err());
+}
+
+#[test]
+fn test_generated_input() {
+    test_generated_input_common::<Day21>();
+}
+
+#[test]
+fn test_against_reference() {
+    // Part two only works when the last step reaches the border of a copy of the map, as in the real input
+    test_against_reference_common::<Day21>(&[5, 11, 17], |solution| {
+        let width = solution.parsed_data.as_ref().unwrap().grid.width();
+        solution.part_two_steps = 2 * width + width / 2;
+    });
+}
@@ -0,0 +1,25 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Generate a snapshot of `size` bricks falling in a 10 x 10 column.
+/// Bricks are placed at increasing heights so that they never intersect, then listed in a random order.
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    let mut top = 0;
+    let mut bricks: Vec<_> = (0..size.max(1))
+        .map(|_| {
+            let length = rng.gen_range(0..4);
+            let (x, y) = (rng.gen_range(0..10), rng.gen_range(0..10));
+            let z = top + rng.gen_range(1..=3);
+            let (end_x, end_y, end_z) = match rng.gen_range(0..3) {
+                0 => ((x + length).min(9), y, z),
+                1 => (x, (y + length).min(9), z),
+                _ => (x, y, z + length),
+            };
+            top = end_z;
+            format!("{x},{y},{z}~{end_x},{end_y},{end_z}\n")
+        })
+        .collect();
+    bricks.shuffle(rng);
+
+    bricks.concat()
+}
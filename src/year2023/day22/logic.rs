@@ -1,15 +1,17 @@
-use crate::day22::models::{Brick, Direction, FallingBricks, Point};
-use ndarray::Array2;
-
-use petgraph::{Graph, Incoming, Outgoing};
 use std::cmp::Reverse;
 use std::collections::{BinaryHeap, VecDeque};
 
+use ndarray::Array2;
+use petgraph::{Graph, Incoming, Outgoing};
+
+use crate::geometry::Axis;
+use crate::year2023::day22::models::{Brick, FallingBricks};
+
 pub fn prepare_data(mut falling_bricks: FallingBricks) -> (FallingBricks, Graph<usize, ()>) {
     // Determinate the grid size
     let ends: Vec<_> = falling_bricks.bricks.iter().map(Brick::end).collect();
-    let max_x = ends.iter().map(Point::x).max().unwrap();
-    let max_y = ends.iter().map(Point::y).max().unwrap();
+    let max_x = ends.iter().map(|end| end.x).max().unwrap();
+    let max_y = ends.iter().map(|end| end.y).max().unwrap();
 
     // Create a graph that will contains the connection between bricks
     let mut graph = Graph::new();
@@ -45,7 +47,7 @@ pub fn prepare_data(mut falling_bricks: FallingBricks) -> (FallingBricks, Graph<
 
         // Update the height_map with this value
         // We add the graph connection at the same time
-        if falling_brick.direction == Direction::Z {
+        if falling_brick.axis == Axis::Z {
             // We know that their is something below this point because we chose it like that.
             if max_z > 0 {
                 graph.add_edge(
@@ -0,0 +1,55 @@
+mod generator;
+mod logic;
+mod models;
+mod parser;
+
+#[cfg(test)]
+mod tests;
+
+use petgraph::Graph;
+use rand::rngs::StdRng;
+
+use crate::models::{AdventSolution, Answer};
+use crate::parser::ParseError;
+use crate::registry::Registration;
+use crate::year2023::day22::generator::generate_input;
+use crate::year2023::day22::logic::{prepare_data, solve_part_one, solve_part_two};
+use crate::year2023::day22::models::FallingBricks;
+use crate::year2023::day22::parser::parse_input;
+
+pub const REGISTRATION: Registration = Registration::new::<Day22>(2023, 22, "Sand Slabs", [true, true]);
+
+#[derive(Default)]
+pub struct Day22 {
+    parsed_data: Option<FallingBricks>,
+    prepared_data: Option<Graph<usize, ()>>,
+}
+
+impl AdventSolution for Day22 {
+    fn parse(&mut self, _data: String) -> Result<(), ParseError> {
+        self.parsed_data = Some(parse_input(_data)?);
+        Ok(())
+    }
+
+    fn prepare(&mut self) {
+        let (bricks, graph) = prepare_data(self.parsed_data.clone().unwrap());
+        self.parsed_data = Some(bricks);
+        self.prepared_data = Some(graph)
+    }
+
+    fn solve_part_one(&self) -> Answer {
+        solve_part_one(self.prepared_data.as_ref().unwrap()).into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        solve_part_two(self.prepared_data.as_ref().unwrap()).into()
+    }
+
+    fn generate_input(&self, size: usize, rng: &mut StdRng) -> Option<String> {
+        Some(generate_input(size, rng))
+    }
+
+    fn render(&self) -> Option<String> {
+        self.parsed_data.as_ref().map(|data| data.to_string())
+    }
+}
@@ -0,0 +1,95 @@
+use std::fmt::{Display, Formatter};
+use std::mem::swap;
+
+use crate::geometry::{Axis, Point3};
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct BrickCoordinates(pub Vec<Point3<usize>>);
+
+impl Display for BrickCoordinates {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, brick) in self.0.iter().enumerate() {
+            if i != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", brick)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone)]
+pub struct Brick {
+    pub start_offset: Point3<usize>,
+    pub offsets: Vec<Point3<usize>>,
+    pub end: Point3<usize>,
+    pub axis: Axis,
+}
+
+impl Brick {
+    pub fn new(mut start: Point3<usize>, mut end: Point3<usize>) -> Self {
+        // be sure that start is before the end
+        if start > end {
+            swap(&mut start, &mut end);
+        }
+
+        // normalize the start and end coordinate
+        let start_offset = start;
+        let end = end - start_offset;
+
+        let (offsets, axis) = if end.x != 0 {
+            ((0..=end.x).map(|offset| Point3::new(offset, 0, 0)).collect(), Axis::X)
+        } else if end.y != 0 {
+            ((0..=end.y).map(|offset| Point3::new(0, offset, 0)).collect(), Axis::Y)
+        } else {
+            ((0..=end.z).map(|offset| Point3::new(0, 0, offset)).collect(), Axis::Z)
+        };
+
+        Self {
+            start_offset,
+            offsets,
+            end,
+            axis,
+        }
+    }
+
+    pub fn end(&self) -> Point3<usize> {
+        self.end + self.start_offset
+    }
+
+    pub fn coordinates(&self) -> BrickCoordinates {
+        BrickCoordinates(self.offsets.iter().map(|offset| self.start_offset + *offset).collect())
+    }
+
+    pub fn fall(&mut self, z: usize) {
+        self.start_offset.z -= z;
+    }
+}
+
+impl Display for Brick {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} -> {} [{}]",
+            self.start_offset,
+            self.end + self.start_offset,
+            self.coordinates()
+        )
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct FallingBricks {
+    pub bricks: Vec<Brick>,
+}
+
+impl Display for FallingBricks {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Falling bricks:")?;
+        for brick in &self.bricks {
+            writeln!(f, "{}", brick)?
+        }
+
+        Ok(())
+    }
+}
@@ -8,7 +8,9 @@ use nom::multi::many1;
 use nom::sequence::tuple;
 use nom::{IResult, Parser};
 
-use crate::day22::models::{Brick, FallingBricks, Point};
+use crate::geometry::Point3;
+use crate::parser::{finish, ParseError};
+use crate::year2023::day22::models::{Brick, FallingBricks};
 
 fn parse_brick(input: &str) -> IResult<&str, Brick> {
     map_res(
@@ -28,12 +30,12 @@ fn parse_brick(input: &str) -> IResult<&str, Brick> {
         )),
         |(start_x, _, start_y, _, start_z, _, end_x, _, end_y, _, end_z, _)| {
             Ok::<_, ParseIntError>(Brick::new(
-                Point::new(
+                Point3::new(
                     usize::from_str(start_x)?,
                     usize::from_str(start_y)?,
                     usize::from_str(start_z)?,
                 ),
-                Point::new(
+                Point3::new(
                     usize::from_str(end_x)?,
                     usize::from_str(end_y)?,
                     usize::from_str(end_z)?,
@@ -48,11 +50,6 @@ fn parse_falling_bricks(input: &str) -> IResult<&str, FallingBricks> {
     map(many1(parse_brick), |bricks| FallingBricks { bricks }).parse(input)
 }
 
-pub fn parse_input(input: String) -> FallingBricks {
-    let (res, games) = parse_falling_bricks(&input).unwrap();
-    if !res.is_empty() {
-        panic!("Unable to fully parse the input: {}", res);
-    }
-
-    games
+pub fn parse_input(input: String) -> Result<FallingBricks, ParseError> {
+    finish(22, &input, parse_falling_bricks(&input))
 }
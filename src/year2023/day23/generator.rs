@@ -0,0 +1,145 @@
+use std::collections::VecDeque;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Generate a maze of about `size` x `size` tiles (the size is made odd).
+/// A random spanning tree of the maze cells gives a single path between the entrance and the exit,
+/// then a few more walls are removed to create alternative paths.
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    let size = size.max(5) | 1;
+    let cells = size / 2;
+    let mut grid = vec![vec!['#'; size]; size];
+
+    // Depth first search over the cells, located at odd coordinates
+    let mut visited = vec![vec![false; cells]; cells];
+    let mut stack: Vec<(usize, usize)> = vec![(0, 0)];
+    visited[0][0] = true;
+    grid[1][1] = '.';
+    while let Some(&(line, column)) = stack.last() {
+        let mut neighbors: Vec<(usize, usize)> = [(0, 1), (2, 1), (1, 0), (1, 2)]
+            .into_iter()
+            .filter_map(|(d_line, d_column)| {
+                let neighbor = ((line + d_line).checked_sub(1)?, (column + d_column).checked_sub(1)?);
+                (neighbor.0 < cells && neighbor.1 < cells && !visited[neighbor.0][neighbor.1]).then_some(neighbor)
+            })
+            .collect();
+        neighbors.shuffle(rng);
+
+        match neighbors.first() {
+            None => {
+                stack.pop();
+            }
+            Some(&(next_line, next_column)) => {
+                visited[next_line][next_column] = true;
+                grid[2 * next_line + 1][2 * next_column + 1] = '.';
+                grid[line + next_line + 1][column + next_column + 1] = '.';
+                stack.push((next_line, next_column));
+            }
+        }
+    }
+
+    // Open a few walls between two cells
+    for _ in 0..size / 4 {
+        let line = rng.gen_range(1..size - 1);
+        let column = rng.gen_range(1..size - 1);
+        if (line % 2 == 1) != (column % 2 == 1) {
+            grid[line][column] = '.';
+        }
+    }
+
+    // Entrance and exit
+    let start = (0, 1);
+    let end = (size - 1, size - 2);
+    grid[start.0][start.1] = '.';
+    grid[end.0][end.1] = '.';
+
+    // Put slopes around the intersections so that the paths between them form a DAG, as in the real inputs.
+    // A path between two intersections goes from the one closest to the entrance to the other one.
+    let distances = distances_from(&grid, start);
+    let is_intersection = |tile: (usize, usize)| neighbors(&grid, tile).len() > 2;
+    let order = |tile: (usize, usize)| (distances[tile.0][tile.1], tile);
+    let mut slopes = vec![];
+    for line in 0..size {
+        for column in 0..size {
+            let intersection = (line, column);
+            if grid[line][column] == '#' || !is_intersection(intersection) {
+                continue;
+            }
+            for first in neighbors(&grid, intersection) {
+                // Follow the path until its other end
+                let (mut previous, mut current) = (intersection, first);
+                while !is_intersection(current) && neighbors(&grid, current).len() == 2 {
+                    let next = neighbors(&grid, current)
+                        .into_iter()
+                        .find(|&tile| tile != previous)
+                        .unwrap();
+                    (previous, current) = (current, next);
+                }
+
+                // Paths leading to the entrance or to a dead end must not be entered
+                let outgoing = if is_intersection(current) {
+                    order(intersection) < order(current)
+                } else {
+                    current == end
+                };
+                if first != start && first != end {
+                    let slope = if outgoing {
+                        slope(intersection, first)
+                    } else {
+                        slope(first, intersection)
+                    };
+                    slopes.push((first, slope));
+                }
+            }
+        }
+    }
+    for ((line, column), slope) in slopes {
+        grid[line][column] = slope;
+    }
+
+    grid.into_iter()
+        .map(|line| line.into_iter().collect::<String>() + "\n")
+        .collect()
+}
+
+fn neighbors(grid: &[Vec<char>], (line, column): (usize, usize)) -> Vec<(usize, usize)> {
+    [
+        (line.wrapping_sub(1), column),
+        (line, column.wrapping_sub(1)),
+        (line + 1, column),
+        (line, column + 1),
+    ]
+    .into_iter()
+    .filter(|&(line, column)| line < grid.len() && column < grid[line].len() && grid[line][column] != '#')
+    .collect()
+}
+
+/// Distance of every tile from `start`, without taking the slopes into account
+fn distances_from(grid: &[Vec<char>], start: (usize, usize)) -> Vec<Vec<usize>> {
+    let mut distances = vec![vec![usize::MAX; grid[0].len()]; grid.len()];
+    distances[start.0][start.1] = 0;
+    let mut queue = VecDeque::from([start]);
+    while let Some(tile) = queue.pop_front() {
+        for (line, column) in neighbors(grid, tile) {
+            if distances[line][column] == usize::MAX {
+                distances[line][column] = distances[tile.0][tile.1] + 1;
+                queue.push_back((line, column));
+            }
+        }
+    }
+    distances
+}
+
+/// Slope going from a tile to an adjacent one
+fn slope(from: (usize, usize), to: (usize, usize)) -> char {
+    if to.0 < from.0 {
+        '^'
+    } else if to.0 > from.0 {
+        'v'
+    } else if to.1 < from.1 {
+        '<'
+    } else {
+        '>'
+    }
+}
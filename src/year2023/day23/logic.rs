@@ -1,28 +1,21 @@
-use crate::day23::models::{Map, Tile};
-use crate::day23::PreparedDataType;
-use ndarray::Array2;
+use std::collections::{HashMap, VecDeque};
+
 use petgraph::algo::bellman_ford;
 use petgraph::graph::NodeIndex;
 use petgraph::prelude::EdgeRef;
 use petgraph::{Graph, Undirected};
-use std::collections::{HashMap, VecDeque};
 
-fn is_intersection(map: &Map, line: usize, column: usize) -> bool {
+use crate::cancellation::CancellationToken;
+use crate::grid::Grid;
+use crate::year2023::day23::models::{Map, Tile};
+use crate::year2023::day23::PreparedDataType;
+
+fn is_intersection(map: &Map, position: (usize, usize)) -> bool {
     // The tile is an interception if it has more than two non wall neighbor
-    let mut neighbor_count = 0;
-    if line > 0 && map.grid[line - 1][column] != Tile::Wall {
-        neighbor_count += 1;
-    }
-    if column > 0 && map.grid[line][column - 1] != Tile::Wall {
-        neighbor_count += 1;
-    }
-    if line < map.height - 1 && map.grid[line + 1][column] != Tile::Wall {
-        neighbor_count += 1;
-    }
-    if column < map.width - 1 && map.grid[line][column + 1] != Tile::Wall {
-        neighbor_count += 1;
-    }
-    neighbor_count > 2
+    map.neighbors4(position)
+        .filter(|neighbor| map[*neighbor] != Tile::Wall)
+        .count()
+        > 2
 }
 
 pub fn prepare_data(map: &Map) -> (Graph<(usize, usize), f64>, NodeIndex, NodeIndex) {
@@ -30,25 +23,23 @@ pub fn prepare_data(map: &Map) -> (Graph<(usize, usize), f64>, NodeIndex, NodeIn
     let mut graph = Graph::new();
 
     // Find start and end tiles
-    let start_column = map.grid[0].iter().position(|tile| tile == &Tile::Empty).unwrap();
-    let end_column = map.grid[map.height - 1]
-        .iter()
-        .position(|tile| tile == &Tile::Empty)
-        .unwrap();
+    let end_line = map.height() - 1;
+    let start_column = map.line(0).iter().position(|tile| tile == &Tile::Empty).unwrap();
+    let end_column = map.line(end_line).iter().position(|tile| tile == &Tile::Empty).unwrap();
 
     // Transform them in nodes
     let start_node = graph.add_node((0, start_column));
-    let end_node = graph.add_node((map.height - 1, end_column));
+    let end_node = graph.add_node((end_line, end_column));
 
     // Store graph nodes in a hashmap
     let mut nodes: HashMap<_, _> = HashMap::new();
 
     // Add the start and end nodes in the map
     nodes.insert((0, start_column), start_node);
-    nodes.insert((map.height - 1, end_column), end_node);
+    nodes.insert((end_line, end_column), end_node);
 
     // Keep track of visited nodes
-    let mut visited = Array2::<bool>::default((map.height, map.width));
+    let mut visited = Grid::filled(map.width(), map.height(), false);
 
     // Create a queue for visit
     let mut queue = VecDeque::new();
@@ -57,7 +48,7 @@ pub fn prepare_data(map: &Map) -> (Graph<(usize, usize), f64>, NodeIndex, NodeIn
     queue.push_back((0, start_column, start_node, 0));
 
     while let Some((line, column, mut path_start_node, mut distance)) = queue.pop_front() {
-        if is_intersection(map, line, column) {
+        if is_intersection(map, (line, column)) {
             // Create the graph node or get it from the map
             let node = nodes
                 .entry((line, column))
@@ -73,46 +64,33 @@ pub fn prepare_data(map: &Map) -> (Graph<(usize, usize), f64>, NodeIndex, NodeIn
         }
 
         // If we are at the end, always add the distance and continue
-        if line == map.height - 1 && column == end_column {
+        if line == end_line && column == end_column {
             graph.add_edge(path_start_node, nodes[&(line, column)], (-distance) as f64);
             continue;
         }
 
         // If we already visited the node, don't do anything more
-        if visited[[line, column]] {
+        if visited[(line, column)] {
             continue;
         }
 
-        // Up
-        if line > 0
-            && (map.grid[line][column] == Tile::Empty || map.grid[line][column] == Tile::Top)
-            && (map.grid[line - 1][column] == Tile::Empty || map.grid[line - 1][column] == Tile::Top)
-        {
-            queue.push_back((line - 1, column, path_start_node, distance + 1));
-        }
-        // Left
-        if column > 0
-            && (map.grid[line][column] == Tile::Empty || map.grid[line][column] == Tile::Left)
-            && (map.grid[line][column - 1] == Tile::Empty || map.grid[line][column - 1] == Tile::Left)
-        {
-            queue.push_back((line, column - 1, path_start_node, distance + 1));
-        }
-        // Bot
-        if line < map.height - 1
-            && (map.grid[line][column] == Tile::Empty || map.grid[line][column] == Tile::Bottom)
-            && (map.grid[line + 1][column] == Tile::Empty || map.grid[line + 1][column] == Tile::Bottom)
-        {
-            queue.push_back((line + 1, column, path_start_node, distance + 1));
-        }
-        // Right
-        if column < map.width - 1
-            && (map.grid[line][column] == Tile::Empty || map.grid[line][column] == Tile::Right)
-            && (map.grid[line][column + 1] == Tile::Empty || map.grid[line][column + 1] == Tile::Right)
-        {
-            queue.push_back((line, column + 1, path_start_node, distance + 1));
+        // A slope can only be crossed in its direction
+        for (offset, slope) in [
+            ((-1, 0), Tile::Top),
+            ((0, -1), Tile::Left),
+            ((1, 0), Tile::Bottom),
+            ((0, 1), Tile::Right),
+        ] {
+            if let Some((next_line, next_column)) = map.neighbor((line, column), offset) {
+                if (map[(line, column)] == Tile::Empty || map[(line, column)] == slope)
+                    && (map[(next_line, next_column)] == Tile::Empty || map[(next_line, next_column)] == slope)
+                {
+                    queue.push_back((next_line, next_column, path_start_node, distance + 1));
+                }
+            }
         }
 
-        visited[[line, column]] = true;
+        visited[(line, column)] = true;
     }
     (graph, start_node, end_node)
 }
@@ -130,7 +108,12 @@ fn get_all_paths(
     visited: &mut Vec<bool>,
     max_len: &mut usize,
     current_len: usize,
+    token: &CancellationToken,
 ) {
+    if token.is_cancelled() {
+        return;
+    }
+
     visited[start.index()] = true;
     if start == end {
         if current_len > *max_len {
@@ -140,7 +123,7 @@ fn get_all_paths(
         for edge in graph.edges(start) {
             let target = edge.target();
             if !visited[target.index()] {
-                get_all_paths(graph, target, end, visited, max_len, current_len + edge.weight());
+                get_all_paths(graph, target, end, visited, max_len, current_len + edge.weight(), token);
             }
         }
     }
@@ -148,7 +131,8 @@ fn get_all_paths(
     visited[start.index()] = false;
 }
 
-pub fn solve_part_two((graph, start_node, end_node): &PreparedDataType) -> usize {
+/// Returns `None` if the token is cancelled before every path is explored
+pub fn solve_part_two((graph, start_node, end_node): &PreparedDataType, token: &CancellationToken) -> Option<usize> {
     // First, we need to convert the DAG of -G to an undirected graph of G
     let mut undirected_graph = Graph::new_undirected();
 
@@ -163,20 +147,28 @@ pub fn solve_part_two((graph, start_node, end_node): &PreparedDataType) -> usize
 
     // Add the edges
     for edge in graph.edge_references() {
-        let source = nodes[&graph.node_weight(edge.source()).unwrap()];
-        let target = nodes[&graph.node_weight(edge.target()).unwrap()];
+        let source = nodes[graph.node_weight(edge.source()).unwrap()];
+        let target = nodes[graph.node_weight(edge.target()).unwrap()];
 
         undirected_graph.add_edge(source, target, (-edge.weight()) as usize);
     }
 
     // Get the new start node and end node from the nodes map
-    let start_node = nodes[&graph.node_weight(*start_node).unwrap()];
-    let end_node = nodes[&graph.node_weight(*end_node).unwrap()];
+    let start_node = nodes[graph.node_weight(*start_node).unwrap()];
+    let end_node = nodes[graph.node_weight(*end_node).unwrap()];
 
     // Store visited nodes
     let mut visited = vec![false; undirected_graph.node_count()];
     let mut max_len = 0;
-    get_all_paths(&undirected_graph, start_node, end_node, &mut visited, &mut max_len, 0);
-
-    max_len
+    get_all_paths(
+        &undirected_graph,
+        start_node,
+        end_node,
+        &mut visited,
+        &mut max_len,
+        0,
+        token,
+    );
+
+    (!token.is_cancelled()).then_some(max_len)
 }
@@ -0,0 +1,67 @@
+mod generator;
+mod logic;
+mod models;
+mod parser;
+
+#[cfg(test)]
+mod tests;
+
+use petgraph::graph::NodeIndex;
+use petgraph::Graph;
+use rand::rngs::StdRng;
+
+use crate::cancellation::CancellationToken;
+use crate::models::{AdventSolution, Answer};
+use crate::parser::ParseError;
+use crate::registry::Registration;
+use crate::year2023::day23::generator::generate_input;
+use crate::year2023::day23::logic::{prepare_data, solve_part_one, solve_part_two};
+use crate::year2023::day23::models::{render_graph, Map};
+use crate::year2023::day23::parser::parse_input;
+
+type PreparedDataType = (Graph<(usize, usize), f64>, NodeIndex, NodeIndex);
+
+pub const REGISTRATION: Registration = Registration::new::<Day23>(2023, 23, "A Long Walk", [true, true]);
+
+#[derive(Default)]
+pub struct Day23 {
+    parsed_data: Option<Map>,
+    prepared_data: Option<PreparedDataType>,
+}
+
+impl AdventSolution for Day23 {
+    fn parse(&mut self, _data: String) -> Result<(), ParseError> {
+        self.parsed_data = Some(parse_input(_data)?);
+        Ok(())
+    }
+
+    fn prepare(&mut self) {
+        self.prepared_data = Some(prepare_data(self.parsed_data.as_ref().unwrap()))
+    }
+
+    fn solve_part_one(&self) -> Answer {
+        solve_part_one(self.prepared_data.as_ref().unwrap()).into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        solve_part_two(self.prepared_data.as_ref().unwrap(), &CancellationToken::default())
+            .unwrap()
+            .into()
+    }
+
+    fn solve_part_two_cancellable(&self, token: &CancellationToken) -> Option<Answer> {
+        solve_part_two(self.prepared_data.as_ref().unwrap(), token).map(Answer::from)
+    }
+
+    fn generate_input(&self, size: usize, rng: &mut StdRng) -> Option<String> {
+        Some(generate_input(size, rng))
+    }
+
+    fn render(&self) -> Option<String> {
+        let map = self.parsed_data.as_ref()?;
+        match &self.prepared_data {
+            Some((graph, _, _)) => Some(render_graph(map, graph)),
+            None => Some(map.to_string()),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+
+use colored::Colorize;
+use petgraph::Graph;
+
+use crate::grid::Grid;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum Tile {
+    Empty,
+    Top,
+    Right,
+    Bottom,
+    Left,
+    Wall,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Tile::Empty => ".",
+                Tile::Top => "^",
+                Tile::Right => ">",
+                Tile::Bottom => "v",
+                Tile::Left => "<",
+                Tile::Wall => "#",
+            }
+        )
+    }
+}
+
+pub type Map = Grid<Tile>;
+
+/// Render the map with the nodes of the graph of its paths in red, followed by the edges of the graph.
+/// The edges are weighted by minus the length of their path.
+pub fn render_graph(map: &Map, graph: &Graph<(usize, usize), f64>) -> String {
+    let nodes: HashSet<_> = graph.node_weights().collect();
+
+    let mut rendered = map.render(|position, tile| {
+        if nodes.contains(&position) {
+            "O".red().bold().to_string()
+        } else {
+            tile.to_string()
+        }
+    });
+
+    rendered.push_str("Paths:");
+    for edge in graph.raw_edges() {
+        rendered.push_str(&format!(
+            "\n * {:?} -> {:?}: {} steps",
+            graph[edge.source()],
+            graph[edge.target()],
+            -edge.weight
+        ));
+    }
+
+    rendered
+}
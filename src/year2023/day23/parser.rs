@@ -0,0 +1,37 @@
+use crate::grid::Grid;
+use crate::parser::{check_charset, check_rectangular, ParseError};
+use crate::year2023::day23::models::{Map, Tile};
+
+impl From<char> for Tile {
+    fn from(value: char) -> Self {
+        match value {
+            '.' => Self::Empty,
+            '^' => Self::Top,
+            '>' => Self::Right,
+            'v' => Self::Bottom,
+            '<' => Self::Left,
+            '#' => Self::Wall,
+            _ => unreachable!(),
+        }
+    }
+}
+
+pub fn parse_input(input: String) -> Result<Map, ParseError> {
+    check_charset(23, &input, ".^>v<#")?;
+    check_rectangular(23, &input)?;
+
+    // The hike starts and ends on the path tiles of the first and last lines
+    let lines: Vec<_> = input.lines().collect();
+    for (line, place) in [(lines[0], "first"), (lines[lines.len() - 1], "last")] {
+        if !line.contains('.') {
+            return Err(ParseError::at(
+                23,
+                &input,
+                line,
+                format!("a path tile '.' in the {place} line"),
+            ));
+        }
+    }
+
+    Ok(Grid::parse(&input))
+}
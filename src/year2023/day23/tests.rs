@@ -0,0 +1,38 @@
+use crate::common::{test_examples_common, test_generated_input_common};
+use crate::parser::ParseError;
+use crate::year2023::day23::parser::parse_input;
+use crate::year2023::day23::{Day23, REGISTRATION};
+
+#[test]
+fn test_parse_missing_path_end() {
+    assert_eq!(
+        parse_input("###\n#.#\n#.#\n".to_string()).err(),
+        Some(ParseError {
+            day: 23,
+            line: 1,
+            column: 1,
+            expected: "a path tile '.' in the first line".to_string(),
+            snippet: "###".to_string(),
+        })
+    );
+    assert_eq!(
+        parse_input("#.#\n#>#\n###\n".to_string()).err(),
+        Some(ParseError {
+            day: 23,
+            line: 3,
+            column: 1,
+            expected: "a path tile '.' in the last line".to_string(),
+            snippet: "###".to_string(),
+        })
+    );
+}
+
+#[test]
+fn test_examples() {
+    test_examples_common(&REGISTRATION);
+}
+
+#[test]
+fn test_generated_input() {
+    test_generated_input_common::<Day23>();
+}
@@ -0,0 +1,84 @@
+use std::collections::HashSet;
+
+use rand::Rng;
+
+/// Offsets to the rock velocity of the velocities of the two pairs of hailstones determining it.
+/// The hailstones of a pair move at the same velocity and are hit one nanosecond apart, so the rock velocity differs
+/// from theirs by a divisor of their distance on each axis: 1 or 2 for the first pair, 1 or 3 for the second.
+/// Only the rock velocity is compatible with both pairs.
+const PAIR_OFFSETS: [i128; 2] = [2, -3];
+
+/// Minimum number of hailstones: the one moving at the rock velocity on the x axis and the two pairs
+const MIN_HAILSTONES: usize = 5;
+
+/// Generate at least `MIN_HAILSTONES` hailstones, and `size` if it is more, that a single rock thrown from an integer
+/// position at an integer velocity hits at distinct integer times.
+/// Velocities are small so that many hailstones share a velocity on an axis, as in the real inputs,
+/// the solution relies on these hailstones to find the rock velocity.
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    let count = size.max(MIN_HAILSTONES);
+    let rock_position: [i128; 3] = [(); 3].map(|_| rng.gen_range(100_000_000_000_000..300_000_000_000_000));
+    let max_velocity = count as i128 / 4;
+    let rock_velocity: [i128; 3] = [(); 3].map(|_| rng.gen_range(-max_velocity..=max_velocity));
+
+    // Hit time and velocity of each hailstone
+    let mut times = HashSet::new();
+    let mut hailstones = vec![];
+
+    // The first hailstone moves at the rock velocity on the x axis, the solution relies on such a hailstone.
+    // Two hailstones at the rock velocity on the same axis would have the same position on it.
+    let velocity = random_velocity(rock_velocity, max_velocity, rng);
+    hailstones.push((
+        random_time(&mut times, 1, rng),
+        [rock_velocity[0], velocity[1], velocity[2]],
+    ));
+
+    // The hailstones of a pair stay next to each other when the solution sorts them by velocity
+    for offset in PAIR_OFFSETS {
+        let time = random_time(&mut times, 2, rng);
+        let velocity = rock_velocity.map(|velocity| velocity + offset);
+        hailstones.extend([(time, velocity), (time + 1, velocity)]);
+    }
+
+    while hailstones.len() < count {
+        hailstones.push((
+            random_time(&mut times, 1, rng),
+            random_velocity(rock_velocity, max_velocity, rng),
+        ));
+    }
+
+    hailstones
+        .into_iter()
+        .map(|(time, velocity)| {
+            // Both are at the same position at `time`
+            let position: Vec<_> = (0..3)
+                .map(|axis| rock_position[axis] + time * (rock_velocity[axis] - velocity[axis]))
+                .collect();
+            format!(
+                "{}, {}, {} @ {}, {}, {}\n",
+                position[0], position[1], position[2], velocity[0], velocity[1], velocity[2]
+            )
+        })
+        .collect()
+}
+
+/// Pick the first of `length` consecutive hit times that are not used yet, and mark them as used
+fn random_time(times: &mut HashSet<i128>, length: i128, rng: &mut impl Rng) -> i128 {
+    loop {
+        let time = rng.gen_range(1..1_000_000_000_000_i128);
+        if (time..time + length).all(|time| !times.contains(&time)) {
+            times.extend(time..time + length);
+            return time;
+        }
+    }
+}
+
+/// Pick a velocity that differs from the rock velocity on every axis
+fn random_velocity(rock_velocity: [i128; 3], max_velocity: i128, rng: &mut impl Rng) -> [i128; 3] {
+    loop {
+        let velocity = [(); 3].map(|_| rng.gen_range(-max_velocity..=max_velocity));
+        if (0..3).all(|axis| velocity[axis] != rock_velocity[axis]) {
+            return velocity;
+        }
+    }
+}
@@ -1,7 +1,11 @@
-use crate::day24::models::{Hail, Hailstone};
+use std::collections::HashSet;
+
 use itertools::Itertools;
 use num_prime::nt_funcs::factorize64;
-use std::collections::HashSet;
+
+use crate::assumptions::Assumption;
+use crate::geometry::Axis;
+use crate::year2023::day24::models::{Hail, Hailstone};
 
 #[allow(clippy::comparison_chain)]
 fn compute_intersection(h1: &Hailstone, h2: &Hailstone) -> Option<(i128, i128, i128)> {
@@ -74,11 +78,14 @@ fn get_possibles_b(x0: i128, x1: i128, v: i128) -> HashSet<i128> {
         .collect()
 }
 
-fn get_b_for_x(hail: &Hail) -> HashSet<i128> {
+/// Find every possible rock velocity on an axis using the hailstones that have the same velocity on it.
+/// The hailstones must be sorted by their velocity on this axis.
+/// The result is empty if the problem does not have a solution.
+fn get_b(hailstones: &[Hailstone], axis: Axis) -> HashSet<i128> {
     let mut possible_res = HashSet::new();
-    for (a, b) in hail.hailstones.iter().tuple_windows() {
-        if a.v.x == b.v.x {
-            let possibles_b = get_possibles_b(a.p0.x, b.p0.x, a.v.x);
+    for (a, b) in hailstones.iter().tuple_windows() {
+        if a.v.coordinate(axis) == b.v.coordinate(axis) {
+            let possibles_b = get_possibles_b(a.p0.coordinate(axis), b.p0.coordinate(axis), a.v.coordinate(axis));
             if possible_res.is_empty() {
                 // We didn't have possible values yet, set the set to this result
                 possible_res = possibles_b;
@@ -86,13 +93,9 @@ fn get_b_for_x(hail: &Hail) -> HashSet<i128> {
                 // We had possible values, compute the intersection
                 possible_res = possible_res.intersection(&possibles_b).cloned().collect();
 
-                // The result must not be empty
-                if possible_res.is_empty() {
-                    panic!("The problem does not have a solution")
-                }
-
+                // If the result is empty, the problem does not have a solution.
                 // If the result only have one solution, we got b
-                if possible_res.len() == 1 {
+                if possible_res.len() <= 1 {
                     return possible_res;
                 }
             }
@@ -102,99 +105,94 @@ fn get_b_for_x(hail: &Hail) -> HashSet<i128> {
     possible_res
 }
 
-fn get_b_for_y(hail: &Hail) -> HashSet<i128> {
-    let mut possible_res = HashSet::new();
-    for (a, b) in hail.hailstones.iter().tuple_windows() {
-        if a.v.y == b.v.y {
-            let possibles_b = get_possibles_b(a.p0.y, b.p0.y, a.v.y);
-            if possible_res.is_empty() {
-                // We didn't have possible values yet, set the set to this result
-                possible_res = possibles_b;
-            } else {
-                // We had possible values, compute the intersection
-                possible_res = possible_res.intersection(&possibles_b).cloned().collect();
-
-                // The result must not be empty
-                if possible_res.is_empty() {
-                    panic!("The problem does not have a solution")
-                }
-
-                // If the result only have one solution, we got b
-                if possible_res.len() == 1 {
-                    return possible_res;
-                }
-            }
-        }
-    }
-
-    possible_res
+/// Find every possible rock velocity on the x, y and z axes.
+/// The hailstones are sorted by their velocity on the z axis afterward.
+fn get_b_candidates(hail: &mut Hail) -> [HashSet<i128>; 3] {
+    [Axis::X, Axis::Y, Axis::Z].map(|axis| {
+        hail.hailstones
+            .sort_by_cached_key(|hailstone| hailstone.v.coordinate(axis));
+        get_b(&hail.hailstones, axis)
+    })
 }
 
-fn get_b_for_z(hail: &Hail) -> HashSet<i128> {
-    let mut possible_res = HashSet::new();
-    for (a, b) in hail.hailstones.iter().tuple_windows() {
-        if a.v.z == b.v.z {
-            let possibles_b = get_possibles_b(a.p0.z, b.p0.z, a.v.z);
-            if possible_res.is_empty() {
-                // We didn't have possible values yet, set the set to this result
-                possible_res = possibles_b;
+/// Properties of the hail that part two relies on
+pub fn check_assumptions(hail: &Hail) -> Vec<Assumption> {
+    let candidates = get_b_candidates(&mut hail.clone());
+
+    let mut assumptions: Vec<_> = [
+        (
+            "x_velocity",
+            "Hailstones with the same x velocity must determine the rock x velocity",
+        ),
+        (
+            "y_velocity",
+            "Hailstones with the same y velocity must determine the rock y velocity",
+        ),
+        (
+            "z_velocity",
+            "Hailstones with the same z velocity must determine the rock z velocity",
+        ),
+    ]
+    .into_iter()
+    .zip(&candidates)
+    .map(|((name, description), candidates)| {
+        Assumption::check(name, description, candidates.len() == 1, || {
+            if candidates.is_empty() {
+                "no velocity is compatible with the hailstones sharing a velocity on this axis".to_string()
             } else {
-                // We had possible values, compute the intersection
-                possible_res = possible_res.intersection(&possibles_b).cloned().collect();
-
-                // The result must not be empty
-                if possible_res.is_empty() {
-                    panic!("The problem does not have a solution")
-                }
-
-                // If the result only have one solution, we got b
-                if possible_res.len() == 1 {
-                    return possible_res;
-                }
+                format!(
+                    "{} velocities remain possible: {}",
+                    candidates.len(),
+                    candidates.iter().sorted().join(", ")
+                )
             }
-        }
+        })
+    })
+    .collect();
+
+    if let [Some(b_x), Some(b_y), Some(b_z)] = candidates.map(|candidates| candidates.into_iter().exactly_one().ok()) {
+        assumptions.push(Assumption::check(
+            "shared_velocity",
+            "A hailstone must move at the rock velocity on one of the axes",
+            hail.hailstones
+                .iter()
+                .any(|hailstone| hailstone.v.x == b_x || hailstone.v.y == b_y || hailstone.v.z == b_z),
+            || "no hailstone moves at the rock velocity on any axis".to_string(),
+        ));
     }
 
-    possible_res
+    assumptions
 }
 
 #[allow(clippy::comparison_chain, clippy::if_same_then_else)]
 pub fn solve_part_two(hail: &Hail) -> i128 {
     // Clone hail to be able to modify it
     let mut hail = hail.clone();
+    let [b_x, b_y, b_z] = get_b_candidates(&mut hail);
 
-    // Sort it by vx
-    hail.hailstones.sort_by_cached_key(|hailstone| hailstone.v.x);
-    let b_x = get_b_for_x(&hail);
     let b_x = if b_x.len() == 1 {
         b_x.into_iter().next().unwrap()
     } else if b_x.is_empty() {
-        unreachable!()
+        panic!("The problem does not have a solution")
     } else {
         // example
         unreachable!()
     };
 
-    // Sort it by vy
-    hail.hailstones.sort_by_cached_key(|hailstone| hailstone.v.y);
-    let b_y = get_b_for_y(&hail);
     let b_y = if b_y.len() == 1 {
         b_y.into_iter().next().unwrap()
     } else if b_y.is_empty() {
-        unreachable!()
+        panic!("The problem does not have a solution")
     } else {
         // example
         assert!(b_y.contains(&1));
         1
     };
 
-    // Sort it by vz
-    hail.hailstones.sort_by_cached_key(|hailstone| hailstone.v.z);
-    let b_z = get_b_for_z(&hail);
     let b_z = if b_z.len() == 1 {
         b_z.into_iter().next().unwrap()
     } else if b_z.is_empty() {
-        unreachable!()
+        panic!("The problem does not have a solution")
     } else {
         // example
         assert!(b_z.contains(&2));
@@ -0,0 +1,96 @@
+mod generator;
+mod logic;
+mod models;
+mod parser;
+
+#[cfg(test)]
+mod tests;
+
+use rand::rngs::StdRng;
+
+use crate::assumptions::Assumption;
+use crate::models::{AdventSolution, Answer};
+use crate::parameters::{Parameter, ParameterError, ParameterValue};
+use crate::parser::ParseError;
+use crate::registry::Registration;
+use crate::year2023::day24::generator::generate_input;
+use crate::year2023::day24::logic::{check_assumptions, solve_part_one, solve_part_two};
+use crate::year2023::day24::models::Hail;
+use crate::year2023::day24::parser::parse_input;
+
+pub const REGISTRATION: Registration = Registration::new::<Day24>(2023, 24, "Never Tell Me The Odds", [true, true]);
+
+pub struct Day24 {
+    lower_bound: i128,
+    upper_bound: i128,
+    parsed_data: Option<Hail>,
+}
+
+impl Default for Day24 {
+    fn default() -> Self {
+        Self {
+            lower_bound: 200_000_000_000_000,
+            upper_bound: 400_000_000_000_000,
+            parsed_data: None,
+        }
+    }
+}
+
+impl Day24 {
+    pub fn new(lower_bound: i128, upper_bound: i128) -> Self {
+        Self {
+            lower_bound,
+            upper_bound,
+            parsed_data: None,
+        }
+    }
+}
+
+impl AdventSolution for Day24 {
+    fn parse(&mut self, _data: String) -> Result<(), ParseError> {
+        self.parsed_data = Some(parse_input(_data)?);
+        Ok(())
+    }
+
+    fn solve_part_one(&self) -> Answer {
+        solve_part_one(self.parsed_data.as_ref().unwrap(), self.lower_bound, self.upper_bound).into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        solve_part_two(self.parsed_data.as_ref().unwrap()).into()
+    }
+
+    fn check_assumptions(&self) -> Vec<Assumption> {
+        check_assumptions(self.parsed_data.as_ref().unwrap())
+    }
+
+    fn parameters(&self) -> Vec<Parameter> {
+        let default = Self::default();
+        vec![
+            Parameter::new(
+                "lower_bound",
+                "Lower bound of the test area of part one",
+                ParameterValue::Integer(default.lower_bound),
+            ),
+            Parameter::new(
+                "upper_bound",
+                "Upper bound of the test area of part one",
+                ParameterValue::Integer(default.upper_bound),
+            ),
+        ]
+    }
+
+    fn set_parameter(&mut self, name: &str, value: ParameterValue) -> Result<(), ParameterError> {
+        match (name, value) {
+            ("lower_bound", ParameterValue::Integer(value)) => self.lower_bound = value,
+            ("upper_bound", ParameterValue::Integer(value)) => self.upper_bound = value,
+            _ => unreachable!(),
+        }
+
+        Ok(())
+    }
+
+    fn generate_input(&self, size: usize, rng: &mut StdRng) -> Option<String> {
+        Some(generate_input(size, rng))
+    }
+}
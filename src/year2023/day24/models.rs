@@ -0,0 +1,21 @@
+use crate::geometry::Point3;
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Hailstone {
+    pub p0: Point3<i128>,
+    pub v: Point3<i128>,
+}
+
+impl Hailstone {
+    pub fn new(x: i128, y: i128, z: i128, vx: i128, vy: i128, vz: i128) -> Self {
+        Self {
+            p0: Point3::new(x, y, z),
+            v: Point3::new(vx, vy, vz),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Hail {
+    pub hailstones: Vec<Hailstone>,
+}
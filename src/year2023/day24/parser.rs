@@ -1,11 +1,14 @@
-use crate::day24::models::{Hail, Hailstone};
+use std::str::FromStr;
+
 use nom::bytes::complete::tag;
 use nom::character::complete::{digit1, line_ending, space1};
 use nom::combinator::{map, map_res, opt, recognize};
 use nom::multi::many1;
 use nom::sequence::{pair, tuple};
 use nom::{IResult, Parser};
-use std::str::FromStr;
+
+use crate::parser::{finish, ParseError};
+use crate::year2023::day24::models::{Hail, Hailstone};
 
 fn parse_number(input: &str) -> IResult<&str, i128> {
     map_res(recognize(tuple((opt(tag("-")), digit1))), i128::from_str).parse(input)
@@ -36,11 +39,6 @@ fn parse_hail(input: &str) -> IResult<&str, Hail> {
     map(many1(parse_hailstone), |hailstones| Hail { hailstones }).parse(input)
 }
 
-pub fn parse_input(input: String) -> Hail {
-    let (res, games) = parse_hail(&input).unwrap();
-    if !res.is_empty() {
-        panic!("Unable to fully parse the input: {}", res);
-    }
-
-    games
+pub fn parse_input(input: String) -> Result<Hail, ParseError> {
+    finish(24, &input, parse_hail(&input))
 }
@@ -0,0 +1,34 @@
+use itertools::Itertools;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::common::{test_examples_common, test_generated_input_common};
+use crate::models::AdventSolution;
+use crate::year2023::day24::generator::generate_input;
+use crate::year2023::day24::{Day24, REGISTRATION};
+
+#[test]
+fn test_examples() {
+    test_examples_common(&REGISTRATION);
+}
+
+#[test]
+fn test_generated_input() {
+    test_generated_input_common::<Day24>();
+}
+
+#[test]
+fn test_generated_input_solvable() {
+    // The pairs of hailstones determine the rock velocity whatever the size
+    for (seed, size) in (0..50).cartesian_product([1, 300]) {
+        let mut solution = Day24::default();
+        solution
+            .parse(generate_input(size, &mut StdRng::seed_from_u64(seed)))
+            .unwrap();
+        assert!(solution
+            .check_assumptions()
+            .iter()
+            .all(|assumption| assumption.failure.is_none()));
+        solution.solve_part_two();
+    }
+}
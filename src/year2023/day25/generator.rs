@@ -0,0 +1,53 @@
+use itertools::Itertools;
+use rand::seq::index::sample;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Create a unique name of 3 lowercase letters from an index
+fn component_name(index: usize) -> String {
+    (0..3)
+        .map(|position| char::from(b'a' + (index / 26_usize.pow(position) % 26) as u8))
+        .collect()
+}
+
+/// Generate two groups of `size` components wired together by exactly three wires.
+/// Every component of a group is wired to its 4 closest components in a ring, plus random wires,
+/// so that cutting three wires is only possible between the groups.
+pub fn generate_input(size: usize, rng: &mut impl Rng) -> String {
+    let size = size.clamp(5, 26 * 26 * 26 / 2);
+    let mut names: Vec<_> = (0..2 * size).map(component_name).collect();
+    names.shuffle(rng);
+
+    let mut wires = vec![];
+    for group in 0..2 {
+        let component = |index: usize| group * size + index % size;
+        for index in 0..size {
+            wires.push((component(index), component(index + 1)));
+            wires.push((component(index), component(index + 2)));
+            if rng.gen_bool(0.3) {
+                wires.push((component(index), component(index + rng.gen_range(3..size.max(4)))));
+            }
+        }
+    }
+    for (a, b) in sample(rng, size, 3).into_iter().zip(sample(rng, size, 3)) {
+        wires.push((a, size + b));
+    }
+
+    // Every wire is listed once, on the line of one of its components
+    wires
+        .into_iter()
+        .filter(|(a, b)| a != b)
+        .map(|(a, b)| if rng.gen_bool(0.5) { (a, b) } else { (b, a) })
+        .unique_by(|(a, b)| (*a.min(b), *a.max(b)))
+        .into_group_map()
+        .into_iter()
+        .sorted()
+        .map(|(component, others)| {
+            format!(
+                "{}: {}\n",
+                names[component],
+                others.into_iter().map(|other| &names[other]).join(" ")
+            )
+        })
+        .collect()
+}
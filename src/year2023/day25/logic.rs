@@ -1,11 +1,15 @@
-use crate::day25::models::Connection;
+use std::collections::HashMap;
+
 use petgraph::graph::EdgeIndex;
 use petgraph::{Graph, Undirected};
 use rand::Rng;
 use rayon::prelude::*;
-use std::collections::HashMap;
 
-pub fn solve_part_one(connections: &[Connection]) -> u32 {
+use crate::cancellation::CancellationToken;
+use crate::year2023::day25::models::Connection;
+
+/// Returns `None` if the token is cancelled before a cut of size 3 is found
+pub fn solve_part_one(connections: &[Connection], token: &CancellationToken) -> Option<u32> {
     // Create a graph
     let mut graph = Graph::new_undirected();
 
@@ -14,9 +18,7 @@ pub fn solve_part_one(connections: &[Connection]) -> u32 {
 
     for connection in connections {
         // get the node from the map
-        let start = *nodes
-            .entry(&connection.name)
-            .or_insert_with(|| graph.add_node(1));
+        let start = *nodes.entry(&connection.name).or_insert_with(|| graph.add_node(1));
 
         for other in &connection.others {
             let end = nodes.entry(other).or_insert_with(|| graph.add_node(1));
@@ -28,8 +30,13 @@ pub fn solve_part_one(connections: &[Connection]) -> u32 {
     // Perform the algorithm while the cut is not of size 3
     (0..usize::MAX)
         .into_par_iter()
-        .filter_map(|_| get_result(&graph))
-        .find_any(|_| true)
+        .find_map_any(|_| {
+            if token.is_cancelled() {
+                Some(None)
+            } else {
+                get_result(&graph).map(Some)
+            }
+        })
         .unwrap()
 }
 
@@ -66,7 +73,3 @@ fn get_result(graph: &Graph<u32, i32, Undirected>) -> Option<u32> {
         None
     }
 }
-
-pub fn solve_part_two(_connections: &[Connection]) -> u32 {
-    0
-}
@@ -0,0 +1,50 @@
+mod generator;
+mod logic;
+mod models;
+mod parser;
+
+#[cfg(test)]
+mod tests;
+
+use rand::rngs::StdRng;
+
+use crate::cancellation::CancellationToken;
+use crate::models::{AdventSolution, Answer};
+use crate::parser::ParseError;
+use crate::registry::Registration;
+use crate::year2023::day25::generator::generate_input;
+use crate::year2023::day25::logic::solve_part_one;
+use crate::year2023::day25::models::Connection;
+use crate::year2023::day25::parser::parse_input;
+
+pub const REGISTRATION: Registration = Registration::new::<Day25>(2023, 25, "Snowverload", [true, false]);
+
+#[derive(Default)]
+pub struct Day25 {
+    parsed_data: Option<Vec<Connection>>,
+}
+
+impl AdventSolution for Day25 {
+    fn parse(&mut self, _data: String) -> Result<(), ParseError> {
+        self.parsed_data = Some(parse_input(_data)?);
+        Ok(())
+    }
+
+    fn solve_part_one(&self) -> Answer {
+        solve_part_one(self.parsed_data.as_ref().unwrap(), &CancellationToken::default())
+            .unwrap()
+            .into()
+    }
+
+    fn solve_part_two(&self) -> Answer {
+        Answer::Unimplemented
+    }
+
+    fn solve_part_one_cancellable(&self, token: &CancellationToken) -> Option<Answer> {
+        solve_part_one(self.parsed_data.as_ref().unwrap(), token).map(Answer::from)
+    }
+
+    fn generate_input(&self, size: usize, rng: &mut StdRng) -> Option<String> {
+        Some(generate_input(size, rng))
+    }
+}
@@ -1,4 +1,3 @@
-use crate::day25::models::Connection;
 use nom::bytes::complete::tag;
 use nom::character::complete::{alpha1, line_ending, space1};
 use nom::combinator::{map, opt};
@@ -6,6 +5,9 @@ use nom::multi::{many1, separated_list1};
 use nom::sequence::{terminated, tuple};
 use nom::{IResult, Parser};
 
+use crate::parser::{finish, ParseError};
+use crate::year2023::day25::models::Connection;
+
 fn parse_connection(input: &str) -> IResult<&str, Connection> {
     map(
         terminated(
@@ -20,11 +22,6 @@ fn parse_connection(input: &str) -> IResult<&str, Connection> {
     .parse(input)
 }
 
-pub fn parse_input(input: String) -> Vec<Connection> {
-    let (res, games) = many1(parse_connection).parse(&input).unwrap();
-    if !res.is_empty() {
-        panic!("Unable to fully parse the input: {}", res);
-    }
-
-    games
+pub fn parse_input(input: String) -> Result<Vec<Connection>, ParseError> {
+    finish(25, &input, many1(parse_connection).parse(&input))
 }
@@ -0,0 +1,34 @@
+use std::time::Duration;
+
+use crate::cancellation::CancellationToken;
+use crate::common::{test_examples_common, test_generated_input_common, test_part_two_common};
+use crate::models::{AdventSolution, Answer};
+use crate::year2023::day25::{Day25, REGISTRATION};
+
+static INPUT_EXAMPLE: &str = include_str!("../../../input_examples/2023/day25");
+
+#[test]
+fn test_examples() {
+    test_examples_common(&REGISTRATION);
+}
+
+#[test]
+fn test_part_two() {
+    test_part_two_common(Day25::default(), INPUT_EXAMPLE, Answer::Unimplemented);
+}
+
+#[test]
+fn test_generated_input() {
+    test_generated_input_common::<Day25>();
+}
+
+#[test]
+fn test_cancellation() {
+    // Every cut of a complete graph of 5 nodes has at least 4 edges, the search never ends
+    let mut solution = Day25::default();
+    solution
+        .parse("a: b c d e\nb: c d e\nc: d e\nd: e".to_string())
+        .unwrap();
+    let token = CancellationToken::with_timeout(Duration::from_millis(100));
+    assert_eq!(solution.solve_part_one_cancellable(&token), None);
+}